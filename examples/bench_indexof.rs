@@ -0,0 +1,56 @@
+// Compare the speed of indexof with the previous implementation (version 0.2.0),
+// which compared the search string character by character and called
+// s.chars().nth() in the inner loop.
+// Run with: cargo run --release --example bench_indexof
+use std::time::{Duration, Instant};
+use string_manipulation_utf8::indexof;
+
+// indexof version 0.2.0
+fn indexof_v020(s: &str, searchstring: &str, start_index: usize) -> Option<usize> {
+    if searchstring.is_empty() {
+        return None;
+    }
+
+    let search_len: usize = searchstring.chars().count();
+    let total_len: usize = s.len();
+    let mut match_count: usize;
+    let mut next_index: usize;
+
+    for (char_index, c) in (start_index..).zip(s.chars().skip(start_index)) {
+        if c == searchstring.chars().next().unwrap() {
+            match_count = 1;
+            for sc in searchstring.chars().skip(1) {
+                next_index = char_index + match_count;
+                if next_index >= total_len || s.chars().nth(next_index).unwrap() != sc {
+                    break;
+                }
+                match_count += 1;
+            }
+            if match_count == search_len {
+                return Some(char_index);
+            }
+        }
+    }
+
+    None
+}
+
+fn bench(name: &str, f: impl Fn() -> Option<usize>) -> Duration {
+    let start: Instant = Instant::now();
+    let result: Option<usize> = f();
+    let elapsed: Duration = start.elapsed();
+    println!("{name:<12} {result:?} {elapsed:?}");
+    elapsed
+}
+
+fn main() {
+    // The search strings of tests/tests.rs in a long text. The match is at the end.
+    let text: String = "Test 123 éèçà 123 test home ".repeat(2_000) + "needle";
+
+    for searchstring in ["needle", "test home n", "éèçà 123 x"] {
+        println!("search {searchstring:?} in {} characters", text.chars().count());
+        let old: Duration = bench("0.2.0", || indexof_v020(&text, searchstring, 0));
+        let new: Duration = bench("indexof", || indexof(&text, searchstring, 0));
+        println!("speedup      {:.0}x\n", old.as_secs_f64() / new.as_secs_f64().max(1e-9));
+    }
+}
@@ -0,0 +1,263 @@
+/// CharIndex: a string slice with a table of character byte positions.
+///
+/// For repeated character indexed access to the same (large) string. The
+/// functions of the library count the characters from the start of the string
+/// on every call. CharIndex is built once from a string slice and stores the
+/// byte position of every 32nd character:
+///   character index to byte position: walks at most 31 characters (constant time)
+///   byte position to character index: binary search (logarithmic time)
+/// CharIndex implements the CharString trait, the same methods as str and String.
+/// Example:
+///   let index: CharIndex = CharIndex::new("test éèçà 123 test");
+///   index.substr(5, 4)        => "éèçà"
+///   index.indexof("test", 1)  => Some(14)
+use crate::checked::{check_substr, check_substr_end, check_substring, check_substru};
+use crate::{
+    byte_index, calc_from, calc_substring, clamp_index, indexof_all, indexof_with, match_indices_chars,
+    pad_center, pad_left, pad_right, range_bounds, resolve_char_range, resolve_start, signed_range_bounds, str_concat,
+    str_remove_matches, CenterBias, CharPattern, CharString, IndexOfAll, MatchIndicesChars, SearchOptions,
+    StrIndexError,
+};
+use std::borrow::Cow;
+use std::ops::RangeBounds;
+
+const STEP: usize = 32; // Store the byte position of every STEP-th character
+
+#[derive(Clone, Debug)]
+pub struct CharIndex<'a> {
+    s: &'a str,
+    char_count: usize,
+    offsets: Vec<usize>, // Byte position of character 0, STEP, 2 * STEP, ...
+}
+
+impl<'a> CharIndex<'a> {
+    /// Build the character index of a string slice. Walks the string once.
+    pub fn new(s: &'a str) -> Self {
+        let mut offsets: Vec<usize> = Vec::with_capacity(s.len() / STEP + 1);
+        let mut char_count: usize = 0;
+
+        for (pos, _) in s.char_indices() {
+            if char_count.is_multiple_of(STEP) {
+                offsets.push(pos);
+            }
+            char_count += 1;
+        }
+
+        CharIndex { s, char_count, offsets }
+    }
+
+    /// The indexed string slice.
+    pub fn as_str(&self) -> &'a str {
+        self.s
+    }
+
+    /// Number of characters of the string.
+    pub fn char_count(&self) -> usize {
+        self.char_count
+    }
+
+    /// Byte position of the character with index 'char_index'.
+    /// Returns the length of the string in bytes if 'char_index' is equal to or
+    /// greater than the number of characters.
+    pub fn byte_index(&self, char_index: usize) -> usize {
+        if char_index >= self.char_count {
+            return self.s.len();
+        }
+
+        let pos: usize = self.offsets[char_index / STEP];
+        pos + byte_index(&self.s[pos..], char_index % STEP)
+    }
+
+    /// Character index of the character at byte position 'byte_index'.
+    /// If 'byte_index' is not on a character boundary, returns the index of the
+    /// character that contains the byte.
+    /// Returns the number of characters if 'byte_index' is equal to or greater
+    /// than the length of the string in bytes.
+    pub fn char_index(&self, byte_index: usize) -> usize {
+        if byte_index >= self.s.len() {
+            return self.char_count;
+        }
+
+        let block: usize = match self.offsets.binary_search(&byte_index) {
+            Ok(block) => return block * STEP,
+            Err(block) => block - 1, // offsets[0] is 0, block >= 1
+        };
+
+        // Count the characters that start between the stored position and byte_index
+        let count: usize = self.s.as_bytes()[self.offsets[block] + 1..=byte_index]
+            .iter()
+            .filter(|&&b| (b & 0xC0) != 0x80)
+            .count();
+        block * STEP + count
+    }
+
+    /// String slice from character index 'start' up to and *excluding* 'end'.
+    fn slice(&self, start: usize, end: usize) -> &'a str {
+        let start_byte: usize = self.byte_index(start);
+        let end_byte: usize = self.byte_index(end.max(start));
+
+        &self.s[start_byte..end_byte]
+    }
+
+    /// Remove the characters from character index 'start' up to and *excluding*
+    /// 'end'. Returns the borrowed string if nothing is removed.
+    fn remove(&self, start: usize, end: usize) -> Cow<'a, str> {
+        if start >= end || start >= self.char_count {
+            return Cow::Borrowed(self.s);
+        }
+
+        let start_byte: usize = self.byte_index(start);
+        let end_byte: usize = self.byte_index(end);
+        Cow::Owned(str_concat!(&self.s[..start_byte], &self.s[end_byte..]))
+    }
+}
+
+impl<'a> From<&'a str> for CharIndex<'a> {
+    fn from(s: &'a str) -> Self {
+        CharIndex::new(s)
+    }
+}
+
+impl CharString for CharIndex<'_> {
+    fn indexof<P: CharPattern>(&self, mut pattern: P, start_index: usize) -> Option<usize> {
+        if start_index >= self.char_count {
+            return None;
+        }
+
+        let from: usize = self.byte_index(start_index);
+        pattern
+            .find_in(&self.s[from..])
+            .map(|(pos, _)| self.char_index(from + pos))
+    }
+
+    fn indexof_with(&self, searchstring: &str, start_index: usize, options: SearchOptions) -> Option<usize> {
+        indexof_with(self.s, searchstring, start_index, options)
+    }
+
+    fn lastindexof<P: CharPattern>(&self, mut pattern: P, from_index: isize) -> Option<usize> {
+        let from: usize = calc_from(self.char_count, from_index)?;
+        pattern
+            .rfind_in(self.s, self.byte_index(from))
+            .map(|(pos, _)| self.char_index(pos))
+    }
+
+    fn indexof_all<P: CharPattern>(&self, pattern: P, overlapping: bool) -> IndexOfAll<'_, P> {
+        indexof_all(self.s, pattern, overlapping)
+    }
+
+    fn match_indices_chars<P: CharPattern>(&self, pattern: P, overlapping: bool) -> MatchIndicesChars<'_, P> {
+        match_indices_chars(self.s, pattern, overlapping)
+    }
+
+    fn substr(&self, start_index: isize, length: isize) -> String {
+        self.substr_slice(start_index, length).to_owned()
+    }
+
+    fn substru(&self, start_index: usize, length: usize) -> String {
+        self.substru_slice(start_index, length).to_owned()
+    }
+
+    fn substr_end(&self, start_index: isize) -> String {
+        self.substr_end_slice(start_index).to_owned()
+    }
+
+    fn substring(&self, start_index: isize, end_index: isize) -> String {
+        self.substring_slice(start_index, end_index).to_owned()
+    }
+
+    fn str_remove(&self, start_index: isize, length: isize) -> String {
+        self.str_remove_cow(start_index, length).into_owned()
+    }
+
+    fn str_removeu(&self, start_index: usize, length: usize) -> String {
+        self.remove(start_index, start_index.saturating_add(length)).into_owned()
+    }
+
+    fn str_insert(&self, index: isize, insert: &str) -> String {
+        self.str_splice(index, 0, insert)
+    }
+
+    fn str_splice(&self, start_index: isize, delete_count: isize, insert: &str) -> String {
+        let start: usize = clamp_index(self.char_count, start_index);
+        let start_byte: usize = self.byte_index(start);
+        let end_byte: usize = self.byte_index(start.saturating_add(delete_count.max(0) as usize));
+        str_concat!(&self.s[..start_byte], insert, &self.s[end_byte..])
+    }
+
+    fn str_remove_matches<P: CharPattern>(&self, pattern: P) -> String {
+        str_remove_matches(self.s, pattern)
+    }
+
+    fn substr_slice(&self, start_index: isize, length: isize) -> &str {
+        let (start, end) = resolve_char_range(self.char_count, start_index, length);
+        self.slice(start, end)
+    }
+
+    fn substru_slice(&self, start_index: usize, length: usize) -> &str {
+        self.slice(start_index, start_index.saturating_add(length))
+    }
+
+    fn substr_end_slice(&self, start_index: isize) -> &str {
+        match resolve_start(self.char_count, start_index) {
+            Some(start) => &self.s[self.byte_index(start)..],
+            None => "",
+        }
+    }
+
+    fn substring_slice(&self, start_index: isize, end_index: isize) -> &str {
+        let (start, end) = calc_substring(start_index, end_index);
+        self.slice(start, end)
+    }
+
+    fn char_slice<R: RangeBounds<usize>>(&self, range: R) -> &str {
+        let (start, end) = range_bounds(&range);
+        self.slice(start, end)
+    }
+
+    fn char_slice_signed<R: RangeBounds<isize>>(&self, range: R) -> &str {
+        let (start, end) = signed_range_bounds(self.char_count, &range);
+        self.slice(start, end)
+    }
+
+    fn str_remove_cow(&self, start_index: isize, length: isize) -> Cow<'_, str> {
+        let (start, end) = resolve_char_range(self.char_count, start_index, length);
+        self.remove(start, end)
+    }
+
+    fn try_substr(&self, start_index: isize, length: isize) -> Result<String, StrIndexError> {
+        let (start, end) = check_substr(self.char_count, start_index, length)?;
+        Ok(self.slice(start, end).to_owned())
+    }
+
+    fn try_substru(&self, start_index: usize, length: usize) -> Result<String, StrIndexError> {
+        let (start, end) = check_substru(self.char_count, start_index, length)?;
+        Ok(self.slice(start, end).to_owned())
+    }
+
+    fn try_substr_end(&self, start_index: isize) -> Result<String, StrIndexError> {
+        let start: usize = check_substr_end(self.char_count, start_index)?;
+        Ok(self.s[self.byte_index(start)..].to_owned())
+    }
+
+    fn try_substring(&self, start_index: isize, end_index: isize) -> Result<String, StrIndexError> {
+        let (start, end) = check_substring(self.char_count, start_index, end_index)?;
+        Ok(self.slice(start, end).to_owned())
+    }
+
+    fn try_str_remove(&self, start_index: isize, length: isize) -> Result<String, StrIndexError> {
+        let (start, end) = check_substr(self.char_count, start_index, length)?;
+        Ok(self.remove(start, end).into_owned())
+    }
+
+    fn pad_left(&self, width: usize, fill: &str) -> String {
+        pad_left(self.s, width, fill)
+    }
+
+    fn pad_right(&self, width: usize, fill: &str) -> String {
+        pad_right(self.s, width, fill)
+    }
+
+    fn pad_center(&self, width: usize, fill: &str, bias: CenterBias) -> String {
+        pad_center(self.s, width, fill, bias)
+    }
+}
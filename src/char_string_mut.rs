@@ -0,0 +1,76 @@
+/// CharStringMut: in-place character indexed editing of a String.
+///
+/// The CharString methods return a new String. CharStringMut edits the String
+/// buffer in place with replace_range: no new String per edit, the buffer only
+/// grows if the inserted text doesn't fit in the capacity.
+/// Indexes are character indexes. The methods never panic: indexes past the end
+/// of the string are treated as the end of the string.
+/// Example:
+///   let mut s: String = "test éèçà 123".to_owned();
+///   s.remove_chars(4, 5);              // "test 123"
+///   s.insert_at_char(5, "àç ");        // "test àç 123"
+///   s.replace_char_range(0..4, "a");   // "a àç 123"
+use crate::{byte_index, range_bounds};
+use std::ops::{Range, RangeBounds};
+
+pub trait CharStringMut {
+    /// Remove 'length' characters, beginning at character index 'start_index'.
+    fn remove_chars(&mut self, start_index: usize, length: usize);
+
+    /// Insert a string before the character at index 'index'.
+    /// Appends the string if 'index' is equal to or greater than the number of characters.
+    fn insert_at_char(&mut self, index: usize, insert: &str);
+
+    /// Replace a range of characters with a string.
+    /// If the start of the range is past the end of the string, the string is appended.
+    fn replace_char_range<R: RangeBounds<usize>>(&mut self, range: R, replace_with: &str);
+
+    /// Keep the first 'new_length' characters.
+    /// No effect if 'new_length' is equal to or greater than the number of characters.
+    fn truncate_chars(&mut self, new_length: usize);
+
+    /// Keep only a range of characters, remove the characters before and after it.
+    fn retain_char_range<R: RangeBounds<usize>>(&mut self, range: R);
+}
+
+impl CharStringMut for String {
+    fn remove_chars(&mut self, start_index: usize, length: usize) {
+        let bytes: Range<usize> = byte_range(self, start_index, start_index.saturating_add(length));
+        if !bytes.is_empty() {
+            self.replace_range(bytes, "");
+        }
+    }
+
+    fn insert_at_char(&mut self, index: usize, insert: &str) {
+        let pos: usize = byte_index(self, index);
+        self.insert_str(pos, insert);
+    }
+
+    fn replace_char_range<R: RangeBounds<usize>>(&mut self, range: R, replace_with: &str) {
+        let (start, end) = range_bounds(&range);
+        let bytes: Range<usize> = byte_range(self, start, end);
+        self.replace_range(bytes, replace_with);
+    }
+
+    fn truncate_chars(&mut self, new_length: usize) {
+        let pos: usize = byte_index(self, new_length);
+        self.truncate(pos);
+    }
+
+    fn retain_char_range<R: RangeBounds<usize>>(&mut self, range: R) {
+        let (start, end) = range_bounds(&range);
+        let bytes: Range<usize> = byte_range(self, start, end);
+        self.truncate(bytes.end);
+        self.replace_range(..bytes.start, "");
+    }
+}
+
+/// Byte range of the characters from index 'start' up to and *excluding* 'end'.
+/// Indexes past the end of the string are treated as the end of the string.
+/// If 'end' is less than 'start', the range is empty.
+fn byte_range(s: &str, start: usize, end: usize) -> Range<usize> {
+    let start_byte: usize = byte_index(s, start);
+    let end_byte: usize = start_byte + byte_index(&s[start_byte..], end.saturating_sub(start));
+
+    start_byte..end_byte
+}
@@ -0,0 +1,131 @@
+/// Checked functions: same as substr, substru, substr_end, substring and
+/// str_remove, but return an error (StrIndexError) if an argument is out of
+/// range, instead of an empty or unchanged string.
+use crate::{byte_index, slice_chars, str_concat, StrIndexError};
+
+/// Unsigned argument for StrIndexError.
+#[inline]
+fn to_isize(value: usize) -> isize {
+    isize::try_from(value).unwrap_or(isize::MAX)
+}
+
+/// Check the arguments of substr for a string with 'char_len' characters.
+/// Returns the character range (start, end), 'end' not included.
+pub(crate) fn check_substr(char_len: usize, start_index: isize, length: isize) -> Result<(usize, usize), StrIndexError> {
+    let start_error = StrIndexError::StartIndex { index: start_index, char_len };
+    let length_error = StrIndexError::Length { length, char_len };
+
+    // Negative start_index, count backwards from the end
+    let start: usize = if start_index >= 0 {
+        start_index as usize
+    } else {
+        char_len.checked_sub(start_index.unsigned_abs()).ok_or(start_error)?
+    };
+
+    if length == 0 {
+        return if start <= char_len { Ok((start, start)) } else { Err(start_error) };
+    }
+    // The first character has to exist
+    if start >= char_len {
+        return Err(start_error);
+    }
+
+    if length > 0 {
+        // Range start .. start + length
+        match start.checked_add(length as usize) {
+            Some(end) if end <= char_len => Ok((start, end)),
+            _ => Err(length_error),
+        }
+    } else {
+        // Negative length counts backwards, including the character at start
+        match (start + 1).checked_sub(length.unsigned_abs()) {
+            Some(first) => Ok((first, start + 1)),
+            None => Err(length_error),
+        }
+    }
+}
+
+/// Check the arguments of substru. Returns the character range (start, end).
+pub(crate) fn check_substru(char_len: usize, start_index: usize, length: usize) -> Result<(usize, usize), StrIndexError> {
+    if start_index > char_len {
+        return Err(StrIndexError::StartIndex { index: to_isize(start_index), char_len });
+    }
+    match start_index.checked_add(length) {
+        Some(end) if end <= char_len => Ok((start_index, end)),
+        _ => Err(StrIndexError::Length { length: to_isize(length), char_len }),
+    }
+}
+
+/// Check the argument of substr_end. Returns the character index of the start.
+pub(crate) fn check_substr_end(char_len: usize, start_index: isize) -> Result<usize, StrIndexError> {
+    crate::resolve_start(char_len, start_index).ok_or(StrIndexError::StartIndex { index: start_index, char_len })
+}
+
+/// Check the arguments of substring. Returns the character range (start, end).
+pub(crate) fn check_substring(char_len: usize, start_index: isize, end_index: isize) -> Result<(usize, usize), StrIndexError> {
+    let in_range = |index: isize| index >= 0 && index as usize <= char_len;
+
+    if !in_range(start_index) {
+        return Err(StrIndexError::StartIndex { index: start_index, char_len });
+    }
+    if !in_range(end_index) {
+        return Err(StrIndexError::EndIndex { index: end_index, char_len });
+    }
+    Ok(crate::calc_substring(start_index, end_index))
+}
+
+// -------------------------------------------------------------------------
+
+/// Same as substr, but returns an error if the start or the end of the
+/// substring is outside the string.
+/// 'start_index' can be negative to count from the end of the string.
+/// A negative 'length' counts backwards from 'start_index' (included).
+/// Examples:
+///   try_substr("0123456789", 2, 3)   => Ok("234")
+///   try_substr("0123456789", 5, -3)  => Ok("345")
+///   try_substr("0123456789", 8, 3)   => Err(StrIndexError::Length { length: 3, char_len: 10 })
+///   try_substr("0123456789", -11, 1) => Err(StrIndexError::StartIndex { index: -11, char_len: 10 })
+pub fn try_substr(s: &str, start_index: isize, length: isize) -> Result<String, StrIndexError> {
+    let (start, end) = check_substr(s.chars().count(), start_index, length)?;
+    Ok(slice_chars(s, start, end).to_owned())
+}
+
+// -------------------------------------------------------------------------
+
+/// Same as substru, but returns an error if the start or the end of the
+/// substring is outside the string.
+pub fn try_substru(s: &str, start_index: usize, length: usize) -> Result<String, StrIndexError> {
+    let (start, end) = check_substru(s.chars().count(), start_index, length)?;
+    Ok(slice_chars(s, start, end).to_owned())
+}
+
+// -------------------------------------------------------------------------
+
+/// Same as substr_end, but returns an error if 'start_index' is outside the string.
+/// 'start_index' can be equal to the number of characters (empty result).
+pub fn try_substr_end(s: &str, start_index: isize) -> Result<String, StrIndexError> {
+    let start: usize = check_substr_end(s.chars().count(), start_index)?;
+    Ok(s[byte_index(s, start)..].to_owned())
+}
+
+// -------------------------------------------------------------------------
+
+/// Same as substring, but returns an error if 'start_index' or 'end_index' is
+/// less than 0 or greater than the number of characters.
+/// If start_index is greater than end_index, they are swapped (JavaScript substring).
+pub fn try_substring(s: &str, start_index: isize, end_index: isize) -> Result<String, StrIndexError> {
+    let (start, end) = check_substring(s.chars().count(), start_index, end_index)?;
+    Ok(slice_chars(s, start, end).to_owned())
+}
+
+// -------------------------------------------------------------------------
+
+/// Same as str_remove, but returns an error if the start or the end of the
+/// range to remove is outside the string. Same rules as try_substr.
+pub fn try_str_remove(s: &str, start_index: isize, length: isize) -> Result<String, StrIndexError> {
+    let (start, end) = check_substr(s.chars().count(), start_index, length)?;
+    let start_byte: usize = byte_index(s, start);
+    let end_byte: usize = start_byte + byte_index(&s[start_byte..], end - start);
+
+    Ok(str_concat!(&s[..start_byte], &s[end_byte..]))
+}
@@ -0,0 +1,62 @@
+/// Error types of the checked (try_) functions and the UTF-16 index conversions.
+use std::fmt;
+
+/// An index or length argument is out of range for the string.
+/// Every variant has the value of the argument and the length of the string
+/// in characters. Unsigned arguments greater than isize::MAX are reported as
+/// isize::MAX.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum StrIndexError {
+    /// The argument 'start_index' is out of range.
+    StartIndex { index: isize, char_len: usize },
+    /// The argument 'end_index' is out of range.
+    EndIndex { index: isize, char_len: usize },
+    /// The argument 'length' goes past the start or the end of the string.
+    Length { length: isize, char_len: usize },
+}
+
+impl fmt::Display for StrIndexError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match *self {
+            StrIndexError::StartIndex { index, char_len } => {
+                write!(f, "start index {index} out of range for a string of {char_len} characters")
+            }
+            StrIndexError::EndIndex { index, char_len } => {
+                write!(f, "end index {index} out of range for a string of {char_len} characters")
+            }
+            StrIndexError::Length { length, char_len } => {
+                write!(f, "length {length} out of range for a string of {char_len} characters")
+            }
+        }
+    }
+}
+
+impl std::error::Error for StrIndexError {}
+
+// -------------------------------------------------------------------------
+
+/// A UTF-16 code unit index can't be converted (utf16 module).
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Utf16IndexError {
+    /// The index is greater than the length of the string, counted in the unit
+    /// of the index (characters or UTF-16 code units).
+    OutOfRange { index: usize, len: usize },
+    /// The UTF-16 index is between the two code units of a surrogate pair
+    /// (inside a character outside the Basic Multilingual Plane, like "😀").
+    SplitSurrogatePair { index: usize },
+}
+
+impl fmt::Display for Utf16IndexError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match *self {
+            Utf16IndexError::OutOfRange { index, len } => {
+                write!(f, "index {index} out of range for a string of length {len}")
+            }
+            Utf16IndexError::SplitSurrogatePair { index } => {
+                write!(f, "UTF-16 index {index} splits a surrogate pair")
+            }
+        }
+    }
+}
+
+impl std::error::Error for Utf16IndexError {}
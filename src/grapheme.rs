@@ -0,0 +1,298 @@
+/// Grapheme cluster indexing.
+///
+/// Same functions as the character indexed functions of the library, but
+/// indexed by extended grapheme clusters (UAX #29, Unicode 14.0.0) instead of
+/// characters (Unicode scalar values).
+/// "e" + combining acute = "\u{0065}\u{0301}" counts as 1 grapheme, while it
+/// counts as 2 characters with substr, indexof, ...
+/// Emoji with skin tone modifiers, ZWJ sequences and flags are 1 grapheme.
+///
+/// The segmentation tables are part of the library (no dependencies).
+use crate::tables::grapheme::{grapheme_category, Gcb};
+
+pub trait GraphemeString {
+    fn graphemes(&self) -> Graphemes<'_>;
+    fn grapheme_count(&self) -> usize;
+    fn grapheme_indexof(&self, searchstring: &str, start_index: usize) -> Option<usize>;
+    fn grapheme_substr(&self, start_index: isize, length: isize) -> String;
+    fn grapheme_substru(&self, start_index: usize, length: usize) -> String;
+    fn grapheme_substr_end(&self, start_index: isize) -> String;
+    fn grapheme_substring(&self, start_index: isize, end_index: isize) -> String;
+    fn grapheme_str_remove(&self, start_index: isize, length: isize) -> String;
+}
+
+impl GraphemeString for str {
+    fn graphemes(&self) -> Graphemes<'_> {
+        graphemes(self)
+    }
+
+    fn grapheme_count(&self) -> usize {
+        grapheme_count(self)
+    }
+
+    fn grapheme_indexof(&self, searchstring: &str, start_index: usize) -> Option<usize> {
+        indexof(self, searchstring, start_index)
+    }
+
+    fn grapheme_substr(&self, start_index: isize, length: isize) -> String {
+        substr(self, start_index, length)
+    }
+
+    fn grapheme_substru(&self, start_index: usize, length: usize) -> String {
+        substru(self, start_index, length)
+    }
+
+    fn grapheme_substr_end(&self, start_index: isize) -> String {
+        substr_end(self, start_index)
+    }
+
+    fn grapheme_substring(&self, start_index: isize, end_index: isize) -> String {
+        substring(self, start_index, end_index)
+    }
+
+    fn grapheme_str_remove(&self, start_index: isize, length: isize) -> String {
+        str_remove(self, start_index, length)
+    }
+}
+
+impl GraphemeString for String {
+    fn graphemes(&self) -> Graphemes<'_> {
+        graphemes(self)
+    }
+
+    fn grapheme_count(&self) -> usize {
+        grapheme_count(self)
+    }
+
+    fn grapheme_indexof(&self, searchstring: &str, start_index: usize) -> Option<usize> {
+        indexof(self, searchstring, start_index)
+    }
+
+    fn grapheme_substr(&self, start_index: isize, length: isize) -> String {
+        substr(self, start_index, length)
+    }
+
+    fn grapheme_substru(&self, start_index: usize, length: usize) -> String {
+        substru(self, start_index, length)
+    }
+
+    fn grapheme_substr_end(&self, start_index: isize) -> String {
+        substr_end(self, start_index)
+    }
+
+    fn grapheme_substring(&self, start_index: isize, end_index: isize) -> String {
+        substring(self, start_index, end_index)
+    }
+
+    fn grapheme_str_remove(&self, start_index: isize, length: isize) -> String {
+        str_remove(self, start_index, length)
+    }
+}
+
+// -------------------------------------------------------------------------
+
+/// Iterator over the extended grapheme clusters of a string.
+/// Each item is a string slice of the original string.
+#[derive(Clone, Debug)]
+pub struct Graphemes<'a> {
+    s: &'a str,
+    pos: usize, // Byte position of the next grapheme
+}
+
+impl<'a> Iterator for Graphemes<'a> {
+    type Item = &'a str;
+
+    fn next(&mut self) -> Option<&'a str> {
+        if self.pos >= self.s.len() {
+            return None;
+        }
+
+        let start: usize = self.pos;
+        self.pos = next_boundary(self.s, start);
+        Some(&self.s[start..self.pos])
+    }
+}
+
+/// Get an iterator over the extended grapheme clusters of a string.
+/// Example:
+///   graphemes("e\u{301}a").collect::<Vec<&str>>() => ["e\u{301}", "a"]
+pub fn graphemes(s: &str) -> Graphemes<'_> {
+    Graphemes { s, pos: 0 }
+}
+
+/// Number of extended grapheme clusters in a string.
+pub fn grapheme_count(s: &str) -> usize {
+    graphemes(s).count()
+}
+
+// -------------------------------------------------------------------------
+
+/// Byte position of the end of the grapheme cluster starting at byte
+/// position 'start'. 'start' must be on a character boundary.
+pub(crate) fn next_boundary(s: &str, start: usize) -> usize {
+    let mut chars = s[start..].char_indices();
+    let mut prev: Gcb = match chars.next() {
+        Some((_, c)) => grapheme_category(c),
+        None => return s.len(),
+    };
+    let mut ri_count: usize = usize::from(prev == Gcb::RegionalIndicator); // Regional indicators in a row
+    let mut emoji: bool = prev == Gcb::ExtendedPictographic; // Extended_Pictographic Extend*
+    let mut emoji_zwj: bool = false; // Extended_Pictographic Extend* ZWJ
+
+    for (i, c) in chars {
+        let next: Gcb = grapheme_category(c);
+        if is_break(prev, next, emoji_zwj, ri_count) {
+            return start + i;
+        }
+
+        emoji_zwj = false;
+        match next {
+            Gcb::ExtendedPictographic => emoji = true,
+            Gcb::Extend => {}
+            Gcb::Zwj => {
+                emoji_zwj = emoji;
+                emoji = false;
+            }
+            _ => emoji = false,
+        }
+        ri_count = if next == Gcb::RegionalIndicator {
+            ri_count + 1
+        } else {
+            0
+        };
+        prev = next;
+    }
+
+    s.len()
+}
+
+/// Grapheme cluster boundary rules GB3 - GB999 between two characters.
+fn is_break(prev: Gcb, next: Gcb, emoji_zwj: bool, ri_count: usize) -> bool {
+    match (prev, next) {
+        (Gcb::Cr, Gcb::Lf) => false,                             // GB3
+        (Gcb::Control | Gcb::Cr | Gcb::Lf, _) => true,           // GB4
+        (_, Gcb::Control | Gcb::Cr | Gcb::Lf) => true,           // GB5
+        (Gcb::L, Gcb::L | Gcb::V | Gcb::Lv | Gcb::Lvt) => false, // GB6
+        (Gcb::Lv | Gcb::V, Gcb::V | Gcb::T) => false,            // GB7
+        (Gcb::Lvt | Gcb::T, Gcb::T) => false,                    // GB8
+        (_, Gcb::Extend | Gcb::Zwj) => false,                    // GB9
+        (_, Gcb::SpacingMark) => false,                          // GB9a
+        (Gcb::Prepend, _) => false,                              // GB9b
+        (Gcb::Zwj, Gcb::ExtendedPictographic) => !emoji_zwj,     // GB11
+        (Gcb::RegionalIndicator, Gcb::RegionalIndicator) => ri_count.is_multiple_of(2), // GB12, GB13
+        _ => true,                                                                      // GB999
+    }
+}
+
+/// Byte positions of all grapheme cluster boundaries, including 0 and the
+/// length of the string. A string with n graphemes has n + 1 boundaries.
+pub(crate) fn boundaries(s: &str) -> Vec<usize> {
+    let mut result: Vec<usize> = Vec::with_capacity(s.len() + 1);
+    let mut pos: usize = 0;
+
+    result.push(0);
+    while pos < s.len() {
+        pos = next_boundary(s, pos);
+        result.push(pos);
+    }
+    result
+}
+
+// -------------------------------------------------------------------------
+
+/// Get the grapheme position from one string into another. Start searching
+/// from grapheme index 'start_index'. Returns None if not found.
+/// A match has to start and end on a grapheme boundary: "e" is not found in
+/// "e\u{301}" (e + combining acute).
+/// Index of the first grapheme is 0.
+pub fn indexof(s: &str, searchstring: &str, start_index: usize) -> Option<usize> {
+    if searchstring.is_empty() {
+        return None;
+    }
+
+    let bounds: Vec<usize> = boundaries(s);
+    let mut from: usize = *bounds.get(start_index)?;
+
+    while let Some(pos) = s[from..].find(searchstring) {
+        let start: usize = from + pos;
+        if let Ok(index) = bounds.binary_search(&start) {
+            if bounds.binary_search(&(start + searchstring.len())).is_ok() {
+                return Some(index);
+            }
+        }
+        from = start + s[start..].chars().next().map_or(1, char::len_utf8);
+    }
+
+    None // No match found
+}
+
+// -------------------------------------------------------------------------
+
+/// Remove a substring from a string. Beginning at grapheme index 'start_index'
+/// and take 'length' graphemes. Index of the first grapheme is 0.
+/// Same rules as str_remove with characters (negative numbers count backwards).
+/// Example:
+///   grapheme::str_remove("ae\u{301}bc", -3, 2) => "ac"
+pub fn str_remove(s: &str, start_index: isize, length: isize) -> String {
+    let bounds: Vec<usize> = boundaries(s);
+    let (start, end) = crate::resolve_char_range(bounds.len() - 1, start_index, length);
+
+    let mut result: String = s.to_owned();
+    result.replace_range(bounds[start]..bounds[end], "");
+    result
+}
+
+// -------------------------------------------------------------------------
+
+/// Get a substring of a string, beginning at grapheme index 'start_index'
+/// and take 'length' graphemes. Index of the first grapheme is 0.
+/// Negative numbers count backwards, same as substr() with characters.
+/// Example:
+///   grapheme::substr("ae\u{301}b", 1, 1) => "e\u{301}"
+pub fn substr(s: &str, start_index: isize, length: isize) -> String {
+    let bounds: Vec<usize> = boundaries(s);
+    let (start, end) = crate::resolve_char_range(bounds.len() - 1, start_index, length);
+
+    s[bounds[start]..bounds[end]].to_owned()
+}
+
+// -------------------------------------------------------------------------
+
+/// Get a substring of a string, beginning at grapheme index 'start_index'
+/// and take 'length' graphemes. Using unsigned start_index and length.
+/// Index of the first grapheme is 0.
+pub fn substru(s: &str, start_index: usize, length: usize) -> String {
+    graphemes(s)
+        .skip(start_index)
+        .take(length)
+        .collect::<String>()
+}
+
+// -------------------------------------------------------------------------
+
+/// Get a substring from grapheme index 'start_index' till end of the string.
+/// 'start_index' can be negative to count backwards from the end.
+/// If start_index exceeds the string boundary limits, return an empty string.
+/// Index of the first grapheme is 0.
+pub fn substr_end(s: &str, start_index: isize) -> String {
+    let bounds: Vec<usize> = boundaries(s);
+
+    match crate::resolve_start(bounds.len() - 1, start_index) {
+        Some(start) => s[bounds[start]..].to_owned(),
+        None => String::new(),
+    }
+}
+
+// -------------------------------------------------------------------------
+
+/// Get a substring of a string beginning at grapheme index start_index up
+/// to and *excluding* the grapheme index end_index.
+/// Same rules as substring() with characters (JavaScript substring).
+/// Index of the first grapheme is 0.
+pub fn substring(s: &str, start_index: isize, end_index: isize) -> String {
+    let bounds: Vec<usize> = boundaries(s);
+    let total_length: usize = bounds.len() - 1;
+    let (start, end) = crate::calc_substring(start_index, end_index);
+
+    s[bounds[start.min(total_length)]..bounds[end.min(total_length)]].to_owned()
+}
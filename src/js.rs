@@ -0,0 +1,272 @@
+/// JavaScript String functions (ECMAScript semantics).
+///
+/// JavaScript strings are indexed by UTF-16 code units, not by characters:
+/// "😀" has length 2 in JavaScript. The functions of this module use UTF-16
+/// code unit indexes and lengths exactly as the ECMAScript specification, so
+/// code ported from JavaScript gives the same results.
+/// Arguments:
+///   Numbers are isize, negative values are handled as in JavaScript.
+///   An optional argument that can be undefined is an Option (None = undefined).
+///   Results that are -1 or undefined in JavaScript are None.
+/// A result that would split a surrogate pair (half of "😀") can't be stored in
+/// a Rust String, the lone surrogate is replaced by U+FFFD (from_utf16_lossy).
+/// Examples:
+///   js::slice("a😀b", 1, Some(3))   => "😀"
+///   js::char_code_at("😀", 1)       => Some(0xDE00)
+///   js::index_of("a😀b", "b", 0)    => Some(3)
+///   js::split("a,b,c", Some(","), Some(2)) => ["a", "b"]
+use crate::utf16;
+
+/// String length in UTF-16 code units, JavaScript 'length'.
+/// Example:
+///   js::length("a😀") => 3
+pub fn length(s: &str) -> usize {
+    utf16::len(s)
+}
+
+/// Relative index of slice, at, ... : a negative index counts from the end.
+/// The result is limited to 0 ..= len.
+fn relative(len: usize, index: isize) -> usize {
+    if index < 0 {
+        len.saturating_sub(index.unsigned_abs())
+    } else {
+        (index as usize).min(len)
+    }
+}
+
+/// Index limited to 0 ..= len, negative values are 0 (substring, indexOf, ...).
+fn clamp(len: usize, index: isize) -> usize {
+    (index.max(0) as usize).min(len)
+}
+
+fn units(s: &str) -> Vec<u16> {
+    s.encode_utf16().collect()
+}
+
+/// Code unit index of the first match of 'search' in 'units' at 'from' or after.
+fn find_units(units: &[u16], search: &[u16], from: usize) -> Option<usize> {
+    if search.is_empty() {
+        return Some(from);
+    }
+    units
+        .get(from..)?
+        .windows(search.len())
+        .position(|w| w == search)
+        .map(|pos| from + pos)
+}
+
+// -------------------------------------------------------------------------
+
+/// String.prototype.slice(start, end): the code units from 'start' up to and
+/// *excluding* 'end'. Negative values count from the end of the string.
+/// 'end' None (undefined) is the end of the string.
+/// Examples:
+///   js::slice("0123456789", 2, Some(-2)) => "234567"
+///   js::slice("0123456789", -3, None)    => "789"
+pub fn slice(s: &str, start: isize, end: Option<isize>) -> String {
+    let units: Vec<u16> = units(s);
+    let from: usize = relative(units.len(), start);
+    let to: usize = end.map_or(units.len(), |end| relative(units.len(), end));
+
+    if from >= to {
+        return String::new();
+    }
+    String::from_utf16_lossy(&units[from..to])
+}
+
+/// String.prototype.substr(start, length) (legacy, Annex B): 'length' code
+/// units from 'start'. A negative 'start' counts from the end of the string.
+/// 'length' None (undefined) is the rest of the string, a negative length is 0.
+/// Examples:
+///   js::substr("0123456789", -3, Some(2)) => "78"
+///   js::substr("0123456789", 2, Some(-1)) => ""
+pub fn substr(s: &str, start: isize, length: Option<isize>) -> String {
+    let units: Vec<u16> = units(s);
+    let from: usize = relative(units.len(), start);
+    let to: usize = match length {
+        Some(length) => from.saturating_add(clamp(units.len(), length)).min(units.len()),
+        None => units.len(),
+    };
+
+    String::from_utf16_lossy(&units[from..to])
+}
+
+/// String.prototype.substring(start, end): same as the substring function of
+/// this library, but indexed by UTF-16 code units. Negative values are 0, if
+/// 'start' is greater than 'end' they are swapped. 'end' None (undefined) is
+/// the end of the string.
+pub fn substring(s: &str, start: isize, end: Option<isize>) -> String {
+    let units: Vec<u16> = units(s);
+    let a: usize = clamp(units.len(), start);
+    let b: usize = end.map_or(units.len(), |end| clamp(units.len(), end));
+
+    String::from_utf16_lossy(&units[a.min(b)..a.max(b)])
+}
+
+// -------------------------------------------------------------------------
+
+/// String.prototype.at(index): the code unit at 'index' as a string.
+/// A negative index counts from the end. Returns None (undefined) if out of range.
+/// Examples:
+///   js::at("abc", -1)  => Some("c")
+///   js::at("😀", 0)    => Some("\u{FFFD}") (lone surrogate)
+pub fn at(s: &str, index: isize) -> Option<String> {
+    let units: Vec<u16> = units(s);
+    let pos: usize = if index < 0 {
+        units.len().checked_sub(index.unsigned_abs())?
+    } else {
+        index as usize
+    };
+
+    units.get(pos).map(|&unit| String::from_utf16_lossy(&[unit]))
+}
+
+/// String.prototype.charAt(pos): the code unit at 'pos' as a string.
+/// Returns an empty string if 'pos' is out of range (negative values too).
+pub fn char_at(s: &str, pos: isize) -> String {
+    let unit: Option<u16> = char_code_at(s, pos);
+    unit.map_or_else(String::new, |unit| String::from_utf16_lossy(&[unit]))
+}
+
+/// String.prototype.charCodeAt(pos): the UTF-16 code unit at 'pos'.
+/// Returns None (NaN) if 'pos' is out of range.
+/// Example:
+///   js::char_code_at("a😀", 1) => Some(0xD83D)
+pub fn char_code_at(s: &str, pos: isize) -> Option<u16> {
+    let pos: usize = usize::try_from(pos).ok()?;
+    s.encode_utf16().nth(pos)
+}
+
+/// String.prototype.codePointAt(pos): the code point starting at code unit
+/// 'pos'. A surrogate pair gives the code point of the character, a lone
+/// surrogate (second half of a pair) gives the surrogate value.
+/// Returns None (undefined) if 'pos' is out of range.
+/// Examples:
+///   js::code_point_at("a😀", 1) => Some(0x1F600)
+///   js::code_point_at("a😀", 2) => Some(0xDE00)
+pub fn code_point_at(s: &str, pos: isize) -> Option<u32> {
+    let pos: usize = usize::try_from(pos).ok()?;
+    let mut units = s.encode_utf16().skip(pos);
+    let first: u16 = units.next()?;
+
+    if (0xD800..0xDC00).contains(&first) {
+        if let Some(second) = units.next().filter(|u| (0xDC00..0xE000).contains(u)) {
+            return Some(0x10000 + ((u32::from(first) - 0xD800) << 10) + (u32::from(second) - 0xDC00));
+        }
+    }
+    Some(u32::from(first))
+}
+
+// -------------------------------------------------------------------------
+
+/// String.prototype.indexOf(searchString, position): code unit index of the
+/// first match at 'position' or after. 'position' is limited to 0 ..= length.
+/// An empty search string matches at 'position'.
+/// Returns None (-1) if not found.
+/// Examples:
+///   js::index_of("a😀b😀", "😀", 2) => Some(4)
+///   js::index_of("abc", "", 10)      => Some(3)
+pub fn index_of(s: &str, search: &str, position: isize) -> Option<usize> {
+    let units: Vec<u16> = units(s);
+    find_units(&units, &self::units(search), clamp(units.len(), position))
+}
+
+/// String.prototype.lastIndexOf(searchString, position): code unit index of
+/// the last match that starts at 'position' or before. 'position' None
+/// (undefined, NaN) is the end of the string, negative values are 0.
+/// Returns None (-1) if not found.
+/// Examples:
+///   js::last_index_of("canal", "a", None)    => Some(3)
+///   js::last_index_of("canal", "a", Some(2)) => Some(1)
+pub fn last_index_of(s: &str, search: &str, position: Option<isize>) -> Option<usize> {
+    let units: Vec<u16> = units(s);
+    let search: Vec<u16> = self::units(search);
+    let last: usize = units.len().checked_sub(search.len())?;
+    let start: usize = position.map_or(last, |pos| clamp(units.len(), pos)).min(last);
+
+    (0..=start).rev().find(|&i| units[i..i + search.len()] == search[..])
+}
+
+/// String.prototype.includes(searchString, position): true if 'search' is
+/// found at 'position' or after.
+pub fn includes(s: &str, search: &str, position: isize) -> bool {
+    index_of(s, search, position).is_some()
+}
+
+// -------------------------------------------------------------------------
+
+/// String.prototype.padStart(maxLength, fillString): pad the start of the
+/// string with 'fill' (repeated and truncated) to 'max_length' code units.
+/// 'fill' None (undefined) is a space. Returns the string unchanged if its
+/// length is equal to or greater than 'max_length', or if 'fill' is empty.
+/// Examples:
+///   js::pad_start("5", 3, Some("0"))    => "005"
+///   js::pad_start("abc", 10, Some("123")) => "1231231abc"
+pub fn pad_start(s: &str, max_length: usize, fill: Option<&str>) -> String {
+    match padding(s, max_length, fill) {
+        Some(pad) => pad + s,
+        None => s.to_owned(),
+    }
+}
+
+/// String.prototype.padEnd(maxLength, fillString): same as pad_start, but
+/// pads the end of the string.
+/// Example:
+///   js::pad_end("abc", 6, Some("12")) => "abc121"
+pub fn pad_end(s: &str, max_length: usize, fill: Option<&str>) -> String {
+    match padding(s, max_length, fill) {
+        Some(pad) => s.to_owned() + &pad,
+        None => s.to_owned(),
+    }
+}
+
+/// The fill of padStart and padEnd, None if no padding is needed.
+fn padding(s: &str, max_length: usize, fill: Option<&str>) -> Option<String> {
+    let fill: Vec<u16> = units(fill.unwrap_or(" "));
+    let fill_length: usize = max_length.checked_sub(length(s)).filter(|&n| n > 0)?;
+    if fill.is_empty() {
+        return None;
+    }
+
+    let pad: Vec<u16> = fill.iter().copied().cycle().take(fill_length).collect();
+    Some(String::from_utf16_lossy(&pad))
+}
+
+/// String.prototype.repeat(count): the string repeated 'count' times.
+pub fn repeat(s: &str, count: usize) -> String {
+    s.repeat(count)
+}
+
+// -------------------------------------------------------------------------
+
+/// String.prototype.split(separator, limit): split the string at each match of
+/// 'separator', at most 'limit' parts.
+///   'separator' None (undefined): the whole string as the only part.
+///   'separator' "": every UTF-16 code unit is a part (a character outside the
+///     BMP gives two parts with U+FFFD, as lone surrogates).
+///   'limit' None (undefined): no limit (2^32 - 1).
+/// Examples:
+///   js::split("a,b,c", Some(","), None)    => ["a", "b", "c"]
+///   js::split("a,b,c", Some(","), Some(2)) => ["a", "b"]
+///   js::split("", Some(","), None)         => [""]
+///   js::split("", Some(""), None)          => []
+pub fn split(s: &str, separator: Option<&str>, limit: Option<u32>) -> Vec<String> {
+    let limit: usize = limit.unwrap_or(u32::MAX) as usize;
+    if limit == 0 {
+        return Vec::new();
+    }
+    let Some(separator) = separator else {
+        return vec![s.to_owned()];
+    };
+
+    if separator.is_empty() {
+        return s
+            .encode_utf16()
+            .take(limit)
+            .map(|unit| String::from_utf16_lossy(&[unit]))
+            .collect();
+    }
+
+    // A non-empty separator never splits a surrogate pair: split the str
+    s.split(separator).take(limit).map(str::to_owned).collect()
+}
@@ -0,0 +1,162 @@
+/// Text justification and column alignment.
+///
+/// A text is wrapped to lines of 'width' characters (wrap_with) and every line
+/// is aligned: left, right, center or full. Full justification distributes the
+/// extra spaces between the words (as a word processor), the last line of a
+/// paragraph is aligned left. Every line of the text is a paragraph.
+/// The width is counted in characters or terminal columns (WidthMode).
+/// Lines never end with padding spaces.
+/// Examples:
+///   justify("The quick brown fox jumps", 11, Alignment::Right)
+///     => ["  The quick", "  brown fox", "      jumps"]
+///   justify("The quick brown fox jumps", 11, Alignment::Full)
+///     => ["The   quick", "brown   fox", "jumps"]
+use crate::pad::{measure, pad_with, CenterBias, PadSide, WidthMode};
+use crate::wrap::{wrap_with, WrapOptions};
+
+/// Alignment of a line or a column.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum Alignment {
+    #[default]
+    Left,
+    Right,
+    /// The extra space of an odd padding is on the right (CenterBias::Left).
+    Center,
+    /// Spaces between the words up to the width. Same as Left for the last line
+    /// of a paragraph, a line with one word and a column.
+    Full,
+}
+
+// -------------------------------------------------------------------------
+
+/// Wrap a text to lines of at most 'width' characters and align the lines.
+/// Same as justify_with in WidthMode::Chars.
+pub fn justify(s: &str, width: usize, alignment: Alignment) -> Vec<String> {
+    justify_with(s, width, alignment, WidthMode::Chars)
+}
+
+/// Wrap a text to lines of at most 'width' characters or terminal columns
+/// ('mode') and align the lines. An empty line of the text stays an empty line.
+/// A word that is longer than the line is split (WrapOptions::break_words).
+/// Example:
+///   justify_with("日本語 テキスト", 10, Alignment::Center, WidthMode::Columns)
+///     => ["  日本語", " テキスト"]
+pub fn justify_with(s: &str, width: usize, alignment: Alignment, mode: WidthMode) -> Vec<String> {
+    let options = WrapOptions { preserve_newlines: false, mode, ..Default::default() };
+    let mut result: Vec<String> = Vec::new();
+
+    for paragraph in s.lines() {
+        let lines: Vec<String> = wrap_with(paragraph, width, options);
+        if lines.is_empty() {
+            result.push(String::new());
+        }
+        let last: usize = lines.len().saturating_sub(1);
+        for (i, line) in lines.iter().enumerate() {
+            let alignment: Alignment = match alignment {
+                Alignment::Full if i == last => Alignment::Left,
+                _ => alignment,
+            };
+            result.push(justify_line(line, width, alignment, mode));
+        }
+    }
+    result
+}
+
+/// Align one line to 'width' characters or terminal columns ('mode').
+/// The line is not wrapped: a line that is wider than 'width' is returned
+/// unchanged, except with Alignment::Full where the words are separated by one space.
+/// An empty or whitespace-only line is an empty string.
+/// Examples:
+///   justify_line("a b c", 9, Alignment::Full, WidthMode::Chars)   => "a   b   c"
+///   justify_line("a b c", 8, Alignment::Full, WidthMode::Chars)   => "a   b  c"
+///   justify_line("abc", 8, Alignment::Center, WidthMode::Chars)   => "  abc"
+pub fn justify_line(line: &str, width: usize, alignment: Alignment, mode: WidthMode) -> String {
+    if line.trim().is_empty() {
+        return String::new();
+    }
+
+    match alignment {
+        Alignment::Left => line.to_owned(),
+        Alignment::Right => pad_with(line, width, " ", PadSide::Left, mode),
+        Alignment::Center => {
+            // Only the left padding, lines don't end with spaces
+            let line_width: usize = measure(line, mode);
+            let left: usize = width.saturating_sub(line_width) / 2;
+            pad_with(line, line_width + left, " ", PadSide::Left, mode)
+        }
+        Alignment::Full => full_line(line, width, mode),
+    }
+}
+
+/// Distribute the spaces between the words of a line, the first gaps get the
+/// extra spaces if they can't be divided equally.
+fn full_line(line: &str, width: usize, mode: WidthMode) -> String {
+    let words: Vec<&str> = line.split_whitespace().collect();
+    if words.len() < 2 {
+        return line.trim().to_owned();
+    }
+
+    let gaps: usize = words.len() - 1;
+    let words_width: usize = words.iter().map(|word| measure(word, mode)).sum();
+    let spaces: usize = width.saturating_sub(words_width).max(gaps);
+    let (gap, extra) = (spaces / gaps, spaces % gaps);
+
+    let mut result: String = String::with_capacity(line.len() + spaces);
+    for (i, word) in words.iter().enumerate() {
+        if i > 0 {
+            let count: usize = if i <= extra { gap + 1 } else { gap };
+            result.extend(std::iter::repeat_n(' ', count));
+        }
+        result.push_str(word);
+    }
+    result
+}
+
+// -------------------------------------------------------------------------
+
+/// Align the cells of a table in columns. The width of a column is the width
+/// of its widest cell, in characters or terminal columns ('mode').
+/// 'alignments' has the alignment of every column, a missing alignment is
+/// Left (Full is Left too). The cells of a row are joined with 'separator',
+/// a row can have fewer cells than the other rows.
+/// The spaces at the end of a row are removed.
+/// Example:
+///   let rows = [["Item", "Qty"], ["Café", "2"], ["Thé", "12"]];
+///   align_columns(&rows, &[Alignment::Left, Alignment::Right], " | ", WidthMode::Chars)
+///     => ["Item | Qty", "Café |   2", "Thé  |  12"]
+pub fn align_columns<R: AsRef<[S]>, S: AsRef<str>>(
+    rows: &[R],
+    alignments: &[Alignment],
+    separator: &str,
+    mode: WidthMode,
+) -> Vec<String> {
+    let mut widths: Vec<usize> = Vec::new();
+    for row in rows {
+        for (i, cell) in row.as_ref().iter().enumerate() {
+            let cell_width: usize = measure(cell.as_ref(), mode);
+            match widths.get_mut(i) {
+                Some(width) => *width = (*width).max(cell_width),
+                None => widths.push(cell_width),
+            }
+        }
+    }
+
+    rows.iter()
+        .map(|row| {
+            let cells: Vec<String> = row
+                .as_ref()
+                .iter()
+                .enumerate()
+                .map(|(i, cell)| {
+                    let side: PadSide = match alignments.get(i).copied().unwrap_or_default() {
+                        Alignment::Left | Alignment::Full => PadSide::Right,
+                        Alignment::Right => PadSide::Left,
+                        Alignment::Center => PadSide::Center(CenterBias::Left),
+                    };
+                    pad_with(cell.as_ref(), widths[i], " ", side, mode)
+                })
+                .collect();
+            cells.join(separator).trim_end_matches(' ').to_owned()
+        })
+        .collect()
+}
@@ -10,72 +10,354 @@
 /// It can be used as functions or methods from 'str' type (string slice) or 'String' type.
 /// Overview:
 ///   indexof : get the position from one string into another
+///   lastindexof : get the position of the last occurrence from one string into another
+///     indexof, lastindexof, indexof_all and match_indices_chars search a CharPattern:
+///     a string, a character, a set of characters or a function FnMut(char) -> bool
+///   MultiIndexOf : search many strings at once (Aho-Corasick), character positions
+///   indexof_with : indexof ignoring case and/or accents (SearchOptions)
+///   indexof_all, match_indices_chars : iterators over the positions of all occurrences
 ///   substr : get a substring of a string using start index and length (signed values)
 ///   substru : get a substring of a string using start index and length (unsigned values)
 ///   subst_to_end : get a substring from start index till the end of the string
 ///   substring : get a substring of a string using start and end index (not included)
-///   str_remove : Remove a substring from a string
+///   str_remove : Remove a substring from a string (signed values, same range as substr)
+///   str_removeu : Remove a substring from a string (unsigned values)
+///   str_insert : Insert a string at a character index
+///   str_splice : Replace a number of characters with a string (JavaScript splice)
+///   str_remove_matches : Remove all matches of a CharPattern from a string
+///   substr_slice, substru_slice, substr_end_slice, substring_slice : same as
+///     substr, substru, substr_end and substring, returning a string slice (no allocation)
+///   char_slice, char_slice_signed : string slice of a character index range (2..5, -3..)
+///   py_slice, pyslice! : Python slicing with a step, s[start:stop:step]
+///   str_remove_cow : same as str_remove, only allocates if characters are removed
+///   try_substr, try_substru, try_substr_end, try_substring, try_str_remove : return
+///     an error (StrIndexError) if an index or length is out of range
 ///   str_concat! : macro to concatenate multiple strings
+///   resolve_char_range : the character range of substr, to reuse the same index rules
+///   CharStringMut : in-place editing of a String (remove_chars, insert_at_char,
+///     replace_char_range, truncate_chars, retain_char_range)
+///   truncate_chars : truncate to a number of characters with an ellipsis, cut at a character,
+///     word or grapheme boundary, at the end, the start or in the middle (TruncateOptions)
+///   wrap, wrap_with : word wrapping to a number of characters or terminal columns, with
+///     indents (WrapOptions)
+///   justify, justify_with : left, right, center and full justification of paragraphs,
+///     align_columns : column alignment (Alignment)
+///   display_width, substr_width, truncate_width : width and substr measured in terminal
+///     columns (wide CJK and emoji characters 2 columns, combining marks 0)
+///   pad_left, pad_right, pad_center : pad to a number of characters with a fill string
+///     (JavaScript padStart), pad_with : pad to a number of terminal columns (WidthMode)
+///   LineIndex : convert character offsets to (line, column) positions and back (LSP)
+///   CharIndex : string slice with a character index table for fast repeated CharString calls
+///   js : JavaScript String functions, UTF-16 code unit indexes (slice, at, indexOf, split, ...)
+///   utf16 : same functions indexed by UTF-16 code units (Utf16Index), index conversions
+///   positions : convert positions between bytes, characters, UTF-16 and graphemes (Snap)
+///   grapheme : same functions indexed by grapheme clusters (GraphemeString)
+use std::borrow::Cow;
 use std::cmp::Ordering;
+use std::ops::{Bound, RangeBounds};
+
+mod char_index;
+mod char_string_mut;
+mod checked;
+mod error;
+pub mod grapheme;
+pub mod js;
+mod justify;
+mod line_index;
+mod matches;
+mod multi;
+mod pad;
+mod pattern;
+pub mod positions;
+mod search;
+mod tables;
+mod truncate;
+pub mod utf16;
+mod width;
+mod wrap;
+
+pub use char_index::CharIndex;
+pub use char_string_mut::CharStringMut;
+pub use checked::{try_str_remove, try_substr, try_substr_end, try_substring, try_substru};
+pub use error::{StrIndexError, Utf16IndexError};
+pub use grapheme::{GraphemeString, Graphemes};
+pub use justify::{align_columns, justify, justify_line, justify_with, Alignment};
+pub use line_index::{LineIndex, Position, PositionEncoding};
+pub use matches::{indexof_all, match_indices_chars, IndexOfAll, MatchIndicesChars};
+pub use multi::{MultiIndexOf, MultiMatch, MultiMatches};
+pub use pad::{pad_center, pad_left, pad_right, pad_with, CenterBias, PadSide, WidthMode};
+pub use pattern::CharPattern;
+pub use search::{indexof_with, SearchOptions};
+pub use truncate::{truncate_chars, TruncateBoundary, TruncateOptions, TruncatePosition};
+pub use utf16::Utf16Index;
+pub use width::{char_width, display_width, substr_width, truncate_width};
+pub use wrap::{wrap, wrap_with, WrapOptions};
 
 pub trait CharString {
-    fn indexof(&self, searchstring: &str, start_index: usize) -> Option<usize>;
+    fn indexof<P: CharPattern>(&self, pattern: P, start_index: usize) -> Option<usize>;
+    fn indexof_with(&self, searchstring: &str, start_index: usize, options: SearchOptions) -> Option<usize>;
+    fn lastindexof<P: CharPattern>(&self, pattern: P, from_index: isize) -> Option<usize>;
+    fn indexof_all<P: CharPattern>(&self, pattern: P, overlapping: bool) -> IndexOfAll<'_, P>;
+    fn match_indices_chars<P: CharPattern>(&self, pattern: P, overlapping: bool) -> MatchIndicesChars<'_, P>;
     fn substr(&self, start_index: isize, length: isize) -> String;
     fn substru(&self, start_index: usize, length: usize) -> String;
     fn substr_end(&self, start_index: isize) -> String;
     fn substring(&self, start_index: isize, end_index: isize) -> String;
-    fn str_remove(&self, start_index: usize, length: usize) -> String;
+    fn str_remove(&self, start_index: isize, length: isize) -> String;
+    fn str_removeu(&self, start_index: usize, length: usize) -> String;
+    fn str_insert(&self, index: isize, insert: &str) -> String;
+    fn str_splice(&self, start_index: isize, delete_count: isize, insert: &str) -> String;
+    fn str_remove_matches<P: CharPattern>(&self, pattern: P) -> String;
+    fn substr_slice(&self, start_index: isize, length: isize) -> &str;
+    fn substru_slice(&self, start_index: usize, length: usize) -> &str;
+    fn substr_end_slice(&self, start_index: isize) -> &str;
+    fn substring_slice(&self, start_index: isize, end_index: isize) -> &str;
+    fn char_slice<R: RangeBounds<usize>>(&self, range: R) -> &str;
+    fn char_slice_signed<R: RangeBounds<isize>>(&self, range: R) -> &str;
+    fn str_remove_cow(&self, start_index: isize, length: isize) -> Cow<'_, str>;
+    fn try_substr(&self, start_index: isize, length: isize) -> Result<String, StrIndexError>;
+    fn try_substru(&self, start_index: usize, length: usize) -> Result<String, StrIndexError>;
+    fn try_substr_end(&self, start_index: isize) -> Result<String, StrIndexError>;
+    fn try_substring(&self, start_index: isize, end_index: isize) -> Result<String, StrIndexError>;
+    fn try_str_remove(&self, start_index: isize, length: isize) -> Result<String, StrIndexError>;
+    fn pad_left(&self, width: usize, fill: &str) -> String;
+    fn pad_right(&self, width: usize, fill: &str) -> String;
+    fn pad_center(&self, width: usize, fill: &str, bias: CenterBias) -> String;
 }
 
 impl CharString for str {
-    fn indexof(&self, searchstring: &str, start_index: usize) -> Option<usize> {
-        indexof(&self, searchstring, start_index)
+    fn indexof<P: CharPattern>(&self, pattern: P, start_index: usize) -> Option<usize> {
+        indexof(self, pattern, start_index)
+    }
+
+    fn indexof_with(&self, searchstring: &str, start_index: usize, options: SearchOptions) -> Option<usize> {
+        indexof_with(self, searchstring, start_index, options)
+    }
+
+    fn lastindexof<P: CharPattern>(&self, pattern: P, from_index: isize) -> Option<usize> {
+        lastindexof(self, pattern, from_index)
+    }
+
+    fn indexof_all<P: CharPattern>(&self, pattern: P, overlapping: bool) -> IndexOfAll<'_, P> {
+        indexof_all(self, pattern, overlapping)
+    }
+
+    fn match_indices_chars<P: CharPattern>(&self, pattern: P, overlapping: bool) -> MatchIndicesChars<'_, P> {
+        match_indices_chars(self, pattern, overlapping)
     }
 
     fn substr(&self, start_index: isize, length: isize) -> String {
-        substr(&self, start_index, length)
+        substr(self, start_index, length)
     }
 
     fn substru(&self, start_index: usize, length: usize) -> String {
-        substru(&self, start_index, length)
+        substru(self, start_index, length)
     }
 
     fn substr_end(&self, start_index: isize) -> String {
-        substr_end(&self, start_index)
+        substr_end(self, start_index)
     }
 
     fn substring(&self, start_index: isize, end_index: isize) -> String {
-        substring(&self, start_index, end_index)
+        substring(self, start_index, end_index)
     }
 
-    fn str_remove(&self, start_index: usize, length: usize) -> String {
-        str_remove(&self, start_index, length)
+    fn str_remove(&self, start_index: isize, length: isize) -> String {
+        str_remove(self, start_index, length)
+    }
+
+    fn str_removeu(&self, start_index: usize, length: usize) -> String {
+        str_removeu(self, start_index, length)
+    }
+
+    fn str_insert(&self, index: isize, insert: &str) -> String {
+        str_insert(self, index, insert)
+    }
+
+    fn str_splice(&self, start_index: isize, delete_count: isize, insert: &str) -> String {
+        str_splice(self, start_index, delete_count, insert)
+    }
+
+    fn str_remove_matches<P: CharPattern>(&self, pattern: P) -> String {
+        str_remove_matches(self, pattern)
+    }
+
+    fn substr_slice(&self, start_index: isize, length: isize) -> &str {
+        substr_slice(self, start_index, length)
+    }
+
+    fn substru_slice(&self, start_index: usize, length: usize) -> &str {
+        substru_slice(self, start_index, length)
+    }
+
+    fn substr_end_slice(&self, start_index: isize) -> &str {
+        substr_end_slice(self, start_index)
+    }
+
+    fn substring_slice(&self, start_index: isize, end_index: isize) -> &str {
+        substring_slice(self, start_index, end_index)
+    }
+
+    fn char_slice<R: RangeBounds<usize>>(&self, range: R) -> &str {
+        char_slice(self, range)
+    }
+
+    fn char_slice_signed<R: RangeBounds<isize>>(&self, range: R) -> &str {
+        char_slice_signed(self, range)
+    }
+
+    fn str_remove_cow(&self, start_index: isize, length: isize) -> Cow<'_, str> {
+        str_remove_cow(self, start_index, length)
+    }
+
+    fn try_substr(&self, start_index: isize, length: isize) -> Result<String, StrIndexError> {
+        try_substr(self, start_index, length)
+    }
+
+    fn try_substru(&self, start_index: usize, length: usize) -> Result<String, StrIndexError> {
+        try_substru(self, start_index, length)
+    }
+
+    fn try_substr_end(&self, start_index: isize) -> Result<String, StrIndexError> {
+        try_substr_end(self, start_index)
+    }
+
+    fn try_substring(&self, start_index: isize, end_index: isize) -> Result<String, StrIndexError> {
+        try_substring(self, start_index, end_index)
+    }
+
+    fn try_str_remove(&self, start_index: isize, length: isize) -> Result<String, StrIndexError> {
+        try_str_remove(self, start_index, length)
+    }
+
+    fn pad_left(&self, width: usize, fill: &str) -> String {
+        pad_left(self, width, fill)
+    }
+
+    fn pad_right(&self, width: usize, fill: &str) -> String {
+        pad_right(self, width, fill)
+    }
+
+    fn pad_center(&self, width: usize, fill: &str, bias: CenterBias) -> String {
+        pad_center(self, width, fill, bias)
     }
 }
 
 impl CharString for String {
-    fn indexof(&self, searchstring: &str, start_index: usize) -> Option<usize> {
-        indexof(&self, searchstring, start_index)
+    fn indexof<P: CharPattern>(&self, pattern: P, start_index: usize) -> Option<usize> {
+        indexof(self, pattern, start_index)
+    }
+
+    fn indexof_with(&self, searchstring: &str, start_index: usize, options: SearchOptions) -> Option<usize> {
+        indexof_with(self, searchstring, start_index, options)
+    }
+
+    fn lastindexof<P: CharPattern>(&self, pattern: P, from_index: isize) -> Option<usize> {
+        lastindexof(self, pattern, from_index)
+    }
+
+    fn indexof_all<P: CharPattern>(&self, pattern: P, overlapping: bool) -> IndexOfAll<'_, P> {
+        indexof_all(self, pattern, overlapping)
+    }
+
+    fn match_indices_chars<P: CharPattern>(&self, pattern: P, overlapping: bool) -> MatchIndicesChars<'_, P> {
+        match_indices_chars(self, pattern, overlapping)
+    }
+
+    fn str_remove(&self, start_index: isize, length: isize) -> String {
+        str_remove(self, start_index, length)
+    }
+
+    fn str_removeu(&self, start_index: usize, length: usize) -> String {
+        str_removeu(self, start_index, length)
+    }
+
+    fn str_insert(&self, index: isize, insert: &str) -> String {
+        str_insert(self, index, insert)
+    }
+
+    fn str_splice(&self, start_index: isize, delete_count: isize, insert: &str) -> String {
+        str_splice(self, start_index, delete_count, insert)
     }
 
-    fn str_remove(&self, start_index: usize, length: usize) -> String {
-        str_remove(&self, start_index, length)
+    fn str_remove_matches<P: CharPattern>(&self, pattern: P) -> String {
+        str_remove_matches(self, pattern)
     }
 
     fn substr(&self, start_index: isize, length: isize) -> String {
-        substr(&self, start_index, length)
+        substr(self, start_index, length)
     }
 
     fn substru(&self, start_index: usize, length: usize) -> String {
-        substru(&self, start_index, length)
+        substru(self, start_index, length)
     }
 
     fn substr_end(&self, start_index: isize) -> String {
-        substr_end(&self, start_index)
+        substr_end(self, start_index)
     }
 
     fn substring(&self, start_index: isize, end_index: isize) -> String {
-        substring(&self, start_index, end_index)
+        substring(self, start_index, end_index)
+    }
+
+    fn substr_slice(&self, start_index: isize, length: isize) -> &str {
+        substr_slice(self, start_index, length)
+    }
+
+    fn substru_slice(&self, start_index: usize, length: usize) -> &str {
+        substru_slice(self, start_index, length)
+    }
+
+    fn substr_end_slice(&self, start_index: isize) -> &str {
+        substr_end_slice(self, start_index)
+    }
+
+    fn substring_slice(&self, start_index: isize, end_index: isize) -> &str {
+        substring_slice(self, start_index, end_index)
+    }
+
+    fn char_slice<R: RangeBounds<usize>>(&self, range: R) -> &str {
+        char_slice(self, range)
+    }
+
+    fn char_slice_signed<R: RangeBounds<isize>>(&self, range: R) -> &str {
+        char_slice_signed(self, range)
+    }
+
+    fn str_remove_cow(&self, start_index: isize, length: isize) -> Cow<'_, str> {
+        str_remove_cow(self, start_index, length)
+    }
+
+    fn try_substr(&self, start_index: isize, length: isize) -> Result<String, StrIndexError> {
+        try_substr(self, start_index, length)
+    }
+
+    fn try_substru(&self, start_index: usize, length: usize) -> Result<String, StrIndexError> {
+        try_substru(self, start_index, length)
+    }
+
+    fn try_substr_end(&self, start_index: isize) -> Result<String, StrIndexError> {
+        try_substr_end(self, start_index)
+    }
+
+    fn try_substring(&self, start_index: isize, end_index: isize) -> Result<String, StrIndexError> {
+        try_substring(self, start_index, end_index)
+    }
+
+    fn try_str_remove(&self, start_index: isize, length: isize) -> Result<String, StrIndexError> {
+        try_str_remove(self, start_index, length)
+    }
+
+    fn pad_left(&self, width: usize, fill: &str) -> String {
+        pad_left(self, width, fill)
+    }
+
+    fn pad_right(&self, width: usize, fill: &str) -> String {
+        pad_right(self, width, fill)
+    }
+
+    fn pad_center(&self, width: usize, fill: &str, bias: CenterBias) -> String {
+        pad_center(self, width, fill, bias)
     }
 }
 
@@ -113,45 +395,174 @@ macro_rules! str_concat {
 
 // -------------------------------------------------------------------------
 
-/// Theoretically calculate the start and end position within a string with a total of length characters.
-/// Numbers can be negative to count from the end of the string (start_index) and backwards (length).
-/// total_length = total length of the string.
-/// start_index = start index (can be negative).
-/// length = how many characters to count from the start_index (can be negative).
-/// If length is 0, empty string, start and end are equal to 0 (0, 0).
-/// If start_index exceeds the string boundary limits, return an empty string (0, 0).
-/// Returns Tuple (start, end) positions. Result is a range from 'start' index till 'end' index (not included).
-#[inline]
-fn calc_start_end(total_length: usize, start_index: isize, length: isize) -> (usize, usize) {
-    let start: isize;
-    let last: isize;
-    let total_length: isize = total_length as isize;
-
-    if total_length == 0 || length == 0 || start_index < -total_length || start_index >= total_length {
+/// Resolve the character range of substr(start_index, length) within a string
+/// of 'len' characters. Same rules as substr, for reuse in other slicing code:
+///   'start_index' can be negative to count from the end of the string.
+///   'length' can be negative to count backwards from 'start_index' (included).
+///   If length is 0, or start_index exceeds the string boundary limits, the
+///   range is empty: (0, 0).
+///   A length past the start or the end of the string is limited to the string.
+/// Every combination of arguments is defined, the calculation saturates and
+/// never overflows (isize::MIN, isize::MAX, len up to usize::MAX).
+/// Returns Tuple (start, end), a range from 'start' index till 'end' index
+/// (not included), with start <= end <= len.
+/// Examples:
+///   resolve_char_range(10, 2, 3)                  => (2, 5)
+///   resolve_char_range(10, -3, 2)                 => (7, 9)
+///   resolve_char_range(10, 5, -2)                 => (4, 6)
+///   resolve_char_range(10, 2, isize::MAX)         => (2, 10)
+///   resolve_char_range(10, isize::MIN, isize::MIN) => (0, 0)
+pub fn resolve_char_range(len: usize, start_index: isize, length: isize) -> (usize, usize) {
+    if length == 0 {
         return (0, 0);
     }
 
-    let length: isize = length.clamp(-total_length, total_length);
-
     // Negative start_index, count backwards from the end
+    let start: usize = match resolve_start(len, start_index) {
+        Some(start) if start < len => start,
+        _ => return (0, 0), // Out of string boundaries
+    };
+
+    if length > 0 {
+        (start, start.saturating_add(length as usize).min(len))
+    } else {
+        // Negative length, count backwards from start position (included).
+        // start < len, start + 1 can't overflow.
+        ((start + 1).saturating_sub(length.unsigned_abs()), start + 1)
+    }
+}
+
+/// Calculate the character index of 'start_index' within a string with a total
+/// of 'len' characters. A negative start_index counts from the end of the string.
+/// Returns None if start_index is less than -len or greater than len.
+#[inline]
+fn resolve_start(len: usize, start_index: isize) -> Option<usize> {
     if start_index >= 0 {
-        start = start_index
+        Some(start_index as usize).filter(|&start| start <= len)
     } else {
-        start = total_length + start_index
+        len.checked_sub(start_index.unsigned_abs())
     }
+}
 
-    // Negative length, count backwards from start position
-    if length > 0 {
-        last = (start + length - 1).clamp(0, total_length - 1) // Overflow possible: 2, isize::MAX
+/// Calculate the insert position of str_insert and str_splice (JavaScript
+/// splice). A negative index counts from the end of the string.
+/// The result is limited to the string: 0 ..= len.
+#[inline]
+fn clamp_index(len: usize, index: isize) -> usize {
+    if index >= 0 {
+        (index as usize).min(len)
     } else {
-        last = (start + length + 1).clamp(0, total_length + 1)
+        len.saturating_sub(index.unsigned_abs())
     }
+}
 
-    if start > last {
-        (last as usize, (start + 1) as usize)
+/// Calculate the character index to search backwards from (lastindexof).
+/// A negative from_index counts from the end of the string. A from_index past
+/// the end of the string is the last character.
+/// Returns None for an empty string or if a negative from_index exceeds the
+/// string boundary limits.
+#[inline]
+fn calc_from(total_length: usize, from_index: isize) -> Option<usize> {
+    if from_index >= 0 {
+        Some((from_index as usize).min(total_length.checked_sub(1)?))
     } else {
-        (start as usize, (last + 1) as usize)
+        total_length.checked_sub(from_index.unsigned_abs())
+    }
+}
+
+/// Calculate the start and end position of substring (JavaScript substring).
+/// If start_index is greater than end_index, swap start_index and end_index.
+/// Negative values are treated as 0. Values greater than the string length
+/// are not limited, the caller has to treat them as the string length.
+/// Returns Tuple (start, end) positions, 'end' not included.
+#[inline]
+fn calc_substring(start_index: isize, end_index: isize) -> (usize, usize) {
+    let (start, end) = match start_index.cmp(&end_index) {
+        Ordering::Less => (start_index, end_index),
+        Ordering::Greater => (end_index, start_index),
+        Ordering::Equal => return (0, 0),
+    };
+
+    // Any argument value that is less than 0 is treated as if it were 0.
+    (start.max(0) as usize, end.max(0) as usize)
+}
+
+// -------------------------------------------------------------------------
+
+/// Character index range (start, end) of a range argument, 'end' not included.
+/// An unbounded end is usize::MAX, the caller treats it as the end of the string.
+fn range_bounds(range: &impl RangeBounds<usize>) -> (usize, usize) {
+    let start: usize = match range.start_bound() {
+        Bound::Included(&n) => n,
+        Bound::Excluded(&n) => n.saturating_add(1),
+        Bound::Unbounded => 0,
+    };
+    let end: usize = match range.end_bound() {
+        Bound::Included(&n) => n.saturating_add(1),
+        Bound::Excluded(&n) => n,
+        Bound::Unbounded => usize::MAX,
+    };
+    (start, end)
+}
+
+/// Character index range (start, end) of a signed range argument within a
+/// string of 'len' characters, 'end' not included. Negative bounds count from
+/// the end of the string (Python slicing). The bounds are limited to 0 ..= len.
+fn signed_range_bounds(len: usize, range: &impl RangeBounds<isize>) -> (usize, usize) {
+    // Position after the character at index 'n'
+    let after = |n: isize| -> usize {
+        if n >= 0 {
+            (n as usize).saturating_add(1).min(len)
+        } else {
+            len.checked_sub(n.unsigned_abs()).map_or(0, |i| i + 1)
+        }
+    };
+
+    let start: usize = match range.start_bound() {
+        Bound::Included(&n) => clamp_index(len, n),
+        Bound::Excluded(&n) => after(n),
+        Bound::Unbounded => 0,
+    };
+    let end: usize = match range.end_bound() {
+        Bound::Included(&n) => after(n),
+        Bound::Excluded(&n) => clamp_index(len, n),
+        Bound::Unbounded => len,
+    };
+    (start, end)
+}
+
+/// Byte position of the character with index 'char_index'.
+/// Returns the length of the string in bytes if 'char_index' is equal to or
+/// greater than the number of characters.
+#[inline]
+fn byte_index(s: &str, char_index: usize) -> usize {
+    s.char_indices().nth(char_index).map_or(s.len(), |(pos, _)| pos)
+}
+
+/// Largest byte position equal to or less than 'index' that is on a character
+/// boundary. Returns the length of the string if 'index' is past the end.
+#[inline]
+fn floor_char_boundary(s: &str, index: usize) -> usize {
+    if index >= s.len() {
+        return s.len();
+    }
+
+    let mut pos: usize = index;
+    while !s.is_char_boundary(pos) {
+        pos -= 1;
     }
+    pos
+}
+
+/// Get the string slice from character index 'start' up to and *excluding*
+/// character index 'end'. Indexes past the end of the string are treated as
+/// the end of the string.
+#[inline]
+fn slice_chars(s: &str, start: usize, end: usize) -> &str {
+    let start_byte: usize = byte_index(s, start);
+    let end_byte: usize = start_byte + byte_index(&s[start_byte..], end.saturating_sub(start));
+
+    &s[start_byte..end_byte]
 }
 
 // -------------------------------------------------------------------------
@@ -159,94 +570,150 @@ fn calc_start_end(total_length: usize, start_index: isize, length: isize) -> (us
 /// Get the character position from one string into another. Start searching
 /// from character index 'start_index'. Returns None if not found. Index of
 /// the first character is 0.
-pub fn indexof(s: &str, searchstring: &str, start_index: usize) -> Option<usize> {
-    if searchstring.is_empty() {
-        return None;
-    }
-
-    let mut char_index: usize = start_index;
-    let search_len: usize = searchstring.chars().count();
-    let total_len: usize = s.len();
-    let mut match_count: usize; // How many characters match from the search position
-    let mut next_index: usize;
-
-    // Iterate through the String characters from start_index
-    for c in s.chars().skip(start_index) {
-        // Relatively fast because searchstring is mostly short
-        if c == searchstring.chars().next().unwrap() {
-            match_count = 1;
-
-            // sc: char = each subsequent character in searchstring
-            for sc in searchstring.chars().skip(1) {
-                next_index = char_index + match_count;
-                if next_index >= total_len || s.chars().nth(next_index).unwrap() != sc {
-                    break;
-                }
-                match_count += 1;
-            }
-            // If entire search value matches, return index
-            if match_count == search_len {
-                return Some(char_index);
-            }
-        }
-        char_index += 1;
-    }
+/// The search 'pattern' can be a string, a character, a set of characters or
+/// a function (see CharPattern).
+/// Linear time: the search is done on the bytes with the standard library
+/// (two-way string matching), then the characters before the match are counted.
+/// Examples:
+///   indexof("abc123", "c1", 0)                         => Some(2)
+///   indexof("abc123", |c: char| c.is_ascii_digit(), 0)  => Some(3)
+///   indexof("a,b;c", [',', ';'], 2)                    => Some(3)
+pub fn indexof<P: CharPattern>(s: &str, mut pattern: P, start_index: usize) -> Option<usize> {
+    // Byte position of start_index, None if out of bounds
+    let (start_byte, _) = s.char_indices().nth(start_index)?;
+    let (pos_byte, _) = pattern.find_in(&s[start_byte..])?;
+
+    Some(start_index + s[start_byte..start_byte + pos_byte].chars().count())
+}
+
+// -------------------------------------------------------------------------
 
-    None // No match found
+/// Get the character position of the last occurrence from one string into
+/// another. Search backwards, the match starts at character index 'from_index'
+/// or before. Returns None if not found. Index of the first character is 0.
+/// The search 'pattern' can be a string, a character, a set of characters or
+/// a function (see CharPattern).
+/// A negative 'from_index' counts backwards from the end of the string, like substr.
+/// If 'from_index' is greater than the last character index, search from the
+/// end of the string (isize::MAX or -1 search the whole string).
+/// If a negative 'from_index' exceeds the string boundary limits, return None.
+/// Examples:
+///   "test.tar.gz".lastindexof(".", -1)  => Some(8)
+///   "test.tar.gz".lastindexof('.', 7)   => Some(4)
+///   "test.tar.gz".lastindexof(".", -4)  => Some(4)
+pub fn lastindexof<P: CharPattern>(s: &str, mut pattern: P, from_index: isize) -> Option<usize> {
+    let from: usize = calc_from(s.chars().count(), from_index)?;
+    let (pos_byte, _) = pattern.rfind_in(s, byte_index(s, from))?;
+
+    Some(s[..pos_byte].chars().count())
 }
 
 // -------------------------------------------------------------------------
 
 /// Remove a substring from a string. Beginning at character index 'start_index'
 /// and take 'length' characters. Index of the first character is 0.
-pub fn str_remove(s: &str, start_index: usize, length: usize) -> String {
-    let mut chars: std::str::Chars = s.chars();
-    let mut s: String = s.to_owned();
+/// Removes the same characters that substr returns, negative numbers count backwards:
+///  'start_index' from the end of the string.
+///  'length' from 'start_index'.
+/// Never panics. If start_index exceeds the string boundary limits, nothing is removed.
+/// Examples:
+///   str_remove("0123456789", 3, 2)   => "01256789"
+///   str_remove("0123456789", -3, 2)  => "01234569"
+///   str_remove("0123456789", 5, -2)  => "01236789"
+pub fn str_remove(s: &str, start_index: isize, length: isize) -> String {
+    str_remove_cow(s, start_index, length).into_owned()
+}
 
-    if length == 0 {
-        return s;
-    }
-
-    // Counted in bytes
-    let len_byte: usize = s.len(); // Total length in bytes
-    let mut start_byte: usize = 0; // Byte position start_index
-    let mut start_byte_found: bool = false; // start_index byte position found
-    let end_byte: usize; // Byte position end to remove
-    let mut pos_byte: usize = 0; // character byte position
-
-    // Counted in characters
-    let end_index: usize = start_index + length;
-    let mut c: char;
-    let mut pos: usize = 0; // character position
-
-    loop {
-        c = chars.next().unwrap();
-
-        if pos == start_index {
-            start_byte = pos_byte;
-            start_byte_found = true;
-        } else if pos == end_index {
-            end_byte = pos_byte;
-            break;
-        }
+/// Remove a substring from a string. Beginning at character index 'start_index'
+/// and take 'length' characters. Using unsigned start_index and length.
+/// Index of the first character is 0.
+/// Never panics. If start_index exceeds the string boundary limits, nothing is removed.
+pub fn str_removeu(s: &str, start_index: usize, length: usize) -> String {
+    remove_char_range(s, start_index, start_index.saturating_add(length)).into_owned()
+}
 
-        pos += 1;
-        pos_byte += c.len_utf8();
+/// Remove the characters from character index 'start' up to and *excluding*
+/// 'end'. Indexes past the end of the string are treated as the end of the string.
+/// Returns the borrowed string if nothing is removed.
+fn remove_char_range(s: &str, start: usize, end: usize) -> Cow<'_, str> {
+    if start >= end {
+        return Cow::Borrowed(s);
+    }
 
-        // If end of String is reached
-        if pos_byte == len_byte {
-            if start_byte_found {
-                end_byte = pos_byte;
-                break;
-            }
+    let start_byte: usize = byte_index(s, start);
+    if start_byte == s.len() {
+        return Cow::Borrowed(s); // Out of bounds, nothing to remove
+    }
+    let end_byte: usize = start_byte + byte_index(&s[start_byte..], end - start);
+
+    Cow::Owned(str_concat!(&s[..start_byte], &s[end_byte..]))
+}
 
-            // In all other cases return the unmodified string
-            return s;
+// -------------------------------------------------------------------------
+
+/// Remove all the matches of 'pattern' from a string.
+/// The search 'pattern' can be a string, a character, a set of characters or
+/// a function (see CharPattern).
+/// Examples:
+///   str_remove_matches("a,b;c", [',', ';'])              => "abc"
+///   str_remove_matches("a1b22c", |c: char| c.is_numeric()) => "abc"
+pub fn str_remove_matches<P: CharPattern>(s: &str, mut pattern: P) -> String {
+    let mut result: String = String::with_capacity(s.len());
+    let mut pos_byte: usize = 0;
+
+    while let Some((start, end)) = pattern.find_in(&s[pos_byte..]) {
+        result.push_str(&s[pos_byte..pos_byte + start]);
+        pos_byte += end;
+        if start == end {
+            // Empty match: keep the next character and continue after it
+            let Some(c) = s[pos_byte..].chars().next() else {
+                break;
+            };
+            result.push(c);
+            pos_byte += c.len_utf8();
         }
     }
 
-    s.replace_range(start_byte..end_byte, ""); // Remove the range
-    s
+    result.push_str(&s[pos_byte..]);
+    result
+}
+
+// -------------------------------------------------------------------------
+
+/// Remove a substring from a string, same as str_remove.
+/// Returns the borrowed string if nothing is removed (no allocation).
+pub fn str_remove_cow(s: &str, start_index: isize, length: isize) -> Cow<'_, str> {
+    let (start, end) = resolve_char_range(s.chars().count(), start_index, length);
+    remove_char_range(s, start, end)
+}
+
+// -------------------------------------------------------------------------
+
+/// Insert a string before the character at index 'index'.
+/// A negative index counts from the end of the string. An index past the end
+/// appends the string, a negative index before the start inserts at the start.
+/// Examples:
+///   str_insert("test 123", 5, "éè ")  => "test éè 123"
+///   str_insert("test 123", -3, "éè ") => "test éè 123"
+///   str_insert("test", 100, "!")      => "test!"
+pub fn str_insert(s: &str, index: isize, insert: &str) -> String {
+    str_splice(s, index, 0, insert)
+}
+
+/// Remove 'delete_count' characters beginning at character index 'start_index'
+/// and insert a string in their place (JavaScript Array.splice, PHP substr_replace).
+/// A negative start_index counts from the end of the string, limited to the
+/// string as in str_insert. A negative delete_count removes nothing.
+/// Examples:
+///   str_splice("test éèçà 123", 5, 4, "abc")  => "test abc 123"
+///   str_splice("test éèçà 123", -3, 3, "456") => "test éèçà 456"
+///   str_splice("test éèçà 123", 5, 100, "")   => "test "
+pub fn str_splice(s: &str, start_index: isize, delete_count: isize, insert: &str) -> String {
+    let start: usize = clamp_index(s.chars().count(), start_index);
+    let start_byte: usize = byte_index(s, start);
+    let end_byte: usize = start_byte + byte_index(&s[start_byte..], delete_count.max(0) as usize);
+
+    str_concat!(&s[..start_byte], insert, &s[end_byte..])
 }
 
 // -------------------------------------------------------------------------
@@ -271,11 +738,15 @@ pub fn str_remove(s: &str, start_index: usize, length: usize) -> String {
 /// Or substr_end(start_index)
 ///   "0123456789".substr_end(2)  => "23456789"
 pub fn substr(s: &str, start_index: isize, length: isize) -> String {
+    substr_slice(s, start_index, length).to_owned()
+}
+
+/// Same as substr, but returns a string slice of 's' (no allocation).
+pub fn substr_slice(s: &str, start_index: isize, length: isize) -> &str {
     let total_length: usize = s.chars().count();
-    let (start, end) = calc_start_end(total_length, start_index, length);
-    // println!("{} {} {} - {} {}", total_length, start_index, length, start, end); // Debug
+    let (start, end) = resolve_char_range(total_length, start_index, length);
 
-    s.chars().skip(start).take(end - start).collect::<String>()
+    slice_chars(s, start, end)
 }
 
 // -------------------------------------------------------------------------
@@ -288,6 +759,12 @@ pub fn substru(s: &str, start_index: usize, length: usize) -> String {
     s.chars().skip(start_index).take(length).collect::<String>()
 }
 
+/// Same as substru, but returns a string slice of 's' (no allocation).
+#[inline]
+pub fn substru_slice(s: &str, start_index: usize, length: usize) -> &str {
+    slice_chars(s, start_index, start_index.saturating_add(length))
+}
+
 // -------------------------------------------------------------------------
 
 /// Get a substring from character index 'start_index' till end of the string.
@@ -296,23 +773,15 @@ pub fn substru(s: &str, start_index: usize, length: usize) -> String {
 /// (Similar to C++ std::substr() and c# String.Substring.)
 /// Index of the first character is 0.
 pub fn substr_end(s: &str, start_index: isize) -> String {
-    let total_length: isize = s.chars().count() as isize;
+    substr_end_slice(s, start_index).to_owned()
+}
 
-    // Out of string boundaries
-    if (start_index < -total_length) || (start_index > total_length) {
-        return String::new();
+/// Same as substr_end, but returns a string slice of 's' (no allocation).
+pub fn substr_end_slice(s: &str, start_index: isize) -> &str {
+    match resolve_start(s.chars().count(), start_index) {
+        Some(start) => &s[byte_index(s, start)..],
+        None => "",
     }
-
-    let start: isize = if start_index >= 0 {
-        start_index
-    } else {
-        total_length + start_index
-    };
-
-    s.chars()
-        .skip(start as usize)
-        .take((total_length - start) as usize)
-        .collect::<String>()
 }
 
 // -------------------------------------------------------------------------
@@ -326,32 +795,118 @@ pub fn substr_end(s: &str, start_index: isize) -> String {
 /// Any argument value that is greater than string length is treated as if it were string length.
 /// Index of the first character is 0.
 pub fn substring(s: &str, start_index: isize, end_index: isize) -> String {
-    let mut start: isize;
-    let mut end: isize;
-
-    // If start_index is greater than end_index, swap start_index and end_index.
-    match start_index.cmp(&end_index) {
-        Ordering::Less => {
-            start = start_index;
-            end = end_index;
-        }
-        Ordering::Greater => {
-            start = end_index;
-            end = start_index;
-        }
-        Ordering::Equal => {
-            return String::new();
-        }
+    substring_slice(s, start_index, end_index).to_owned()
+}
+
+/// Same as substring, but returns a string slice of 's' (no allocation).
+pub fn substring_slice(s: &str, start_index: isize, end_index: isize) -> &str {
+    let (start, end) = calc_substring(start_index, end_index);
+
+    slice_chars(s, start, end)
+}
+
+// -------------------------------------------------------------------------
+
+/// Get a string slice of a character index range. Index of the first character is 0.
+/// Range bounds past the end of the string are treated as the end of the
+/// string. If the end is before the start, returns an empty string.
+/// Examples:
+///   char_slice("test éèçà 123", 5..9)  => "éèçà"
+///   char_slice("test éèçà 123", ..=3)  => "test"
+///   char_slice("test éèçà 123", 10..)  => "123"
+pub fn char_slice<R: RangeBounds<usize>>(s: &str, range: R) -> &str {
+    let (start, end) = range_bounds(&range);
+    slice_chars(s, start, end)
+}
+
+/// Get a string slice of a signed character index range.
+/// Negative bounds count from the end of the string (Python slicing: s[-3:]).
+/// Range bounds outside the string are limited to the string. If the end is
+/// before the start, returns an empty string.
+/// Examples:
+///   char_slice_signed("test éèçà 123", -3..)    => "123"
+///   char_slice_signed("test éèçà 123", ..-4)    => "test éèçà"
+///   char_slice_signed("test éèçà 123", 5..-4)   => "éèçà"
+///   char_slice_signed("test éèçà 123", -100..4) => "test"
+/// Clippy reports ranges like 5..-4 as empty (reversed_empty_ranges), use
+/// #[allow(clippy::reversed_empty_ranges)] or variables for the bounds.
+pub fn char_slice_signed<R: RangeBounds<isize>>(s: &str, range: R) -> &str {
+    let (start, end) = signed_range_bounds(s.chars().count(), &range);
+    slice_chars(s, start, end)
+}
+
+// -------------------------------------------------------------------------
+
+/// Python slicing with a step: s[start:stop:step], character indexed.
+/// 'start' and 'stop' are None for an omitted index, negative values count
+/// from the end of the string. Same index clamping as CPython (slice.indices):
+///   step > 0: indexes are limited to 0 ..= len, defaults 0 and len.
+///   step < 0: indexes are limited to -1 ..= len - 1, defaults len - 1 and
+///     "before the first character", the characters are taken backwards.
+/// A 'step' of 0 (ValueError in Python) returns an empty string.
+/// Examples:
+///   py_slice("0123456789", None, None, -1)     => "9876543210"   s[::-1]
+///   py_slice("0123456789", Some(1), Some(9), 2) => "1357"         s[1:9:2]
+///   py_slice("0123456789", Some(-5), None, -1)  => "543210"       s[-5::-1]
+pub fn py_slice(s: &str, start: Option<isize>, stop: Option<isize>, step: isize) -> String {
+    if step == 0 {
+        return String::new();
     }
 
-    let total_length: usize = s.chars().count();
-    // If 'start_index' is less than 0 is treated as if it were 0.
-    start = isize::max(0, start);
-    // If end_index is greater than string length, it is treated as if it were string length.
-    end = isize::min(end, total_length as isize);
-
-    s.chars()
-        .skip(start as usize)
-        .take((end - start) as usize)
-        .collect::<String>()
+    let len: usize = s.chars().count();
+
+    if step > 0 {
+        let start: usize = start.map_or(0, |i| clamp_index(len, i));
+        let stop: usize = stop.map_or(len, |i| clamp_index(len, i));
+
+        s.chars()
+            .skip(start)
+            .take(stop.saturating_sub(start))
+            .step_by(step as usize)
+            .collect()
+    } else {
+        // Index + 1, limited to 0 ..= len: 0 is "before the first character"
+        let clamp_after = |i: isize| -> usize {
+            if i < 0 {
+                len.saturating_sub(i.unsigned_abs() - 1)
+            } else {
+                (i as usize).saturating_add(1).min(len)
+            }
+        };
+        let start: usize = start.map_or(len, clamp_after);
+        let stop: usize = stop.map_or(0, clamp_after);
+
+        s.chars()
+            .rev()
+            .skip(len - start)
+            .take(start.saturating_sub(stop))
+            .step_by(step.unsigned_abs())
+            .collect()
+    }
+}
+
+/// Macro for Python slicing with literal indexes: pyslice!(s, [start:stop:step]).
+/// Every part is optional, same as in Python. Uses py_slice.
+/// Examples:
+///   pyslice!("0123456789", [::-1])    => "9876543210"
+///   pyslice!("0123456789", [1:-1:2])  => "1357"
+///   pyslice!("0123456789", [-3:])     => "789"
+/// The indexes have to be literals, use py_slice for variables.
+#[macro_export]
+macro_rules! pyslice {
+    (@opt) => { None };
+    (@opt $value:literal) => { Some($value) };
+    (@step) => { 1 };
+    (@step $value:literal) => { $value };
+    ($s:expr, [$($start:literal)? :: $($step:literal)?]) => {
+        $crate::py_slice($s, $crate::pyslice!(@opt $($start)?), None, $crate::pyslice!(@step $($step)?))
+    };
+    ($s:expr, [$($start:literal)? : $($stop:literal)? $(: $($step:literal)?)?]) => {
+        $crate::py_slice(
+            $s,
+            $crate::pyslice!(@opt $($start)?),
+            $crate::pyslice!(@opt $($stop)?),
+            $crate::pyslice!(@step $($($step)?)?),
+        )
+    };
 }
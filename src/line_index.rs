@@ -0,0 +1,194 @@
+/// LineIndex: convert character offsets to (line, column) positions and back.
+///
+/// Built once from a string, for example for a language server (LSP).
+/// Offsets are character indexes, as used by indexof and substr. Lines end
+/// with "\n", "\r\n" or "\r". Columns are counted in the unit of the
+/// PositionEncoding (same as LSP positionEncoding):
+///   Utf8  : bytes
+///   Utf16 : UTF-16 code units (LSP default)
+///   Utf32 : characters
+/// Both lookups run in logarithmic time: binary search of the line starts and
+/// of the non-ASCII characters.
+/// Example:
+///   let index: LineIndex = LineIndex::new("ab\r\né😀x");
+///   index.position(6, PositionEncoding::Utf16)  => Some(Position { line: 1, column: 3 })
+///   index.offset(Position { line: 1, column: 3 }, PositionEncoding::Utf16) => Some(6)
+use std::ops::Range;
+
+/// Unit of the column of a Position.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum PositionEncoding {
+    /// Bytes of the UTF-8 encoding.
+    Utf8,
+    /// UTF-16 code units.
+    #[default]
+    Utf16,
+    /// Characters (Unicode scalar values).
+    Utf32,
+}
+
+/// Zero-based line and column.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Position {
+    pub line: usize,
+    pub column: usize,
+}
+
+/// A character with more than 1 byte, in the order of the string.
+#[derive(Clone, Copy, Debug)]
+struct WideChar {
+    offset: usize,       // Character offset
+    utf8_before: usize,  // Extra UTF-8 bytes of the wide characters before
+    utf16_before: usize, // Extra UTF-16 code units of the wide characters before
+    utf8_len: usize,
+    utf16_len: usize,
+}
+
+impl WideChar {
+    /// Width of the character in units of 'encoding'.
+    fn width(&self, encoding: PositionEncoding) -> usize {
+        match encoding {
+            PositionEncoding::Utf8 => self.utf8_len,
+            PositionEncoding::Utf16 => self.utf16_len,
+            PositionEncoding::Utf32 => 1,
+        }
+    }
+
+    /// Extra units of this character and the wide characters before.
+    fn extra_through(&self, encoding: PositionEncoding) -> usize {
+        match encoding {
+            PositionEncoding::Utf8 => self.utf8_before + self.utf8_len - 1,
+            PositionEncoding::Utf16 => self.utf16_before + self.utf16_len - 1,
+            PositionEncoding::Utf32 => 0,
+        }
+    }
+
+    /// Position of the character in units of 'encoding' from the start of the string.
+    fn start(&self, encoding: PositionEncoding) -> usize {
+        match encoding {
+            PositionEncoding::Utf8 => self.offset + self.utf8_before,
+            PositionEncoding::Utf16 => self.offset + self.utf16_before,
+            PositionEncoding::Utf32 => self.offset,
+        }
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct LineIndex {
+    lines: Vec<Range<usize>>, // Character offsets of each line, without the line ending
+    wide: Vec<WideChar>,
+    len: usize, // Number of characters
+}
+
+impl LineIndex {
+    /// Build the line index of a string. Walks the string once.
+    pub fn new(s: &str) -> Self {
+        let mut lines: Vec<Range<usize>> = Vec::new();
+        let mut wide: Vec<WideChar> = Vec::new();
+        let mut line_start: usize = 0;
+        let (mut utf8_before, mut utf16_before) = (0, 0);
+        let mut chars = s.chars().enumerate().peekable();
+
+        while let Some((offset, c)) = chars.next() {
+            if !c.is_ascii() {
+                wide.push(WideChar {
+                    offset,
+                    utf8_before,
+                    utf16_before,
+                    utf8_len: c.len_utf8(),
+                    utf16_len: c.len_utf16(),
+                });
+                utf8_before += c.len_utf8() - 1;
+                utf16_before += c.len_utf16() - 1;
+            }
+
+            if c == '\n' || c == '\r' {
+                lines.push(line_start..offset);
+                line_start = offset + 1;
+                if c == '\r' && chars.next_if(|&(_, next)| next == '\n').is_some() {
+                    line_start += 1;
+                }
+            }
+        }
+
+        let len: usize = s.chars().count();
+        lines.push(line_start..len);
+        LineIndex { lines, wide, len }
+    }
+
+    /// Number of lines. A string without line endings (or an empty string) has
+    /// 1 line, a line ending at the end of the string starts an empty last line.
+    pub fn line_count(&self) -> usize {
+        self.lines.len()
+    }
+
+    /// Number of characters of the string.
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// True if the string is empty.
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Character offsets of line 'line', without the line ending.
+    pub fn line_range(&self, line: usize) -> Option<Range<usize>> {
+        self.lines.get(line).cloned()
+    }
+
+    /// Position of character offset 'offset'. 'offset' can be the number of
+    /// characters (end of the string). An offset inside a line ending is on
+    /// the line it ends. Returns None if 'offset' is past the end.
+    pub fn position(&self, offset: usize, encoding: PositionEncoding) -> Option<Position> {
+        if offset > self.len {
+            return None;
+        }
+
+        let line: usize = self.lines.partition_point(|range| range.start <= offset) - 1;
+        let column: usize = self.units(offset, encoding) - self.units(self.lines[line].start, encoding);
+        Some(Position { line, column })
+    }
+
+    /// Character offset of a position. A column past the end of the line is the
+    /// end of the line (before the line ending), a column inside a character
+    /// (a byte of "é", half of a surrogate pair) is the start of that character.
+    /// Returns None if the line doesn't exist.
+    pub fn offset(&self, position: Position, encoding: PositionEncoding) -> Option<usize> {
+        let range: &Range<usize> = self.lines.get(position.line)?;
+        let start: usize = self.units(range.start, encoding);
+        let end: usize = self.units(range.end, encoding);
+        let target: usize = start.saturating_add(position.column).min(end);
+
+        // Wide characters that start before target
+        let k: usize = self.wide.partition_point(|w| w.start(encoding) < target);
+        if k == 0 {
+            return Some(target);
+        }
+        let w: &WideChar = &self.wide[k - 1];
+        if target < w.start(encoding) + w.width(encoding) {
+            return Some(w.offset); // Inside the character
+        }
+        Some(target - w.extra_through(encoding))
+    }
+
+    /// Convert a position from one encoding to another.
+    pub fn convert(&self, position: Position, from: PositionEncoding, to: PositionEncoding) -> Option<Position> {
+        self.position(self.offset(position, from)?, to)
+    }
+
+    /// Units of 'encoding' before character offset 'offset'.
+    fn units(&self, offset: usize, encoding: PositionEncoding) -> usize {
+        let k: usize = self.wide.partition_point(|w| w.offset < offset);
+        match k {
+            0 => offset,
+            _ => offset + self.wide[k - 1].extra_through(encoding),
+        }
+    }
+}
+
+impl From<&str> for LineIndex {
+    fn from(s: &str) -> Self {
+        LineIndex::new(s)
+    }
+}
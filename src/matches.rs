@@ -0,0 +1,105 @@
+/// Iterators over all the matches of a CharPattern, in one pass over the
+/// string. The character position of a match is counted from the previous match.
+use crate::CharPattern;
+use std::iter::FusedIterator;
+
+/// Iterator over the matches of a pattern: (character index, byte index).
+/// Created by match_indices_chars().
+#[derive(Clone, Debug)]
+pub struct MatchIndicesChars<'a, P> {
+    s: &'a str,
+    pattern: P,
+    overlapping: bool,
+    pos_byte: usize, // Byte position to continue searching
+    pos: usize,      // Character position to continue searching
+    done: bool,
+}
+
+impl<P: CharPattern> Iterator for MatchIndicesChars<'_, P> {
+    type Item = (usize, usize);
+
+    fn next(&mut self) -> Option<(usize, usize)> {
+        if self.done {
+            return None;
+        }
+
+        let rest: &str = &self.s[self.pos_byte..];
+        let (start, end) = match self.pattern.find_in(rest) {
+            Some(found) => found,
+            None => {
+                self.done = true;
+                return None;
+            }
+        };
+        let index_byte: usize = self.pos_byte + start;
+        let index: usize = self.pos + rest[..start].chars().count();
+
+        // Continue after the first character of the match, or after the match.
+        // Always continue at the next character or later.
+        let first_len: usize = rest[start..].chars().next().map_or(1, char::len_utf8);
+        if self.overlapping || end - start < first_len {
+            self.pos_byte = index_byte + first_len;
+            self.pos = index + 1;
+        } else {
+            self.pos_byte += end;
+            self.pos = index + rest[start..end].chars().count();
+        }
+        self.done = self.pos_byte > self.s.len();
+
+        Some((index, index_byte))
+    }
+}
+
+impl<P: CharPattern> FusedIterator for MatchIndicesChars<'_, P> {}
+
+/// Iterator over the character positions of the matches of a pattern.
+/// Created by indexof_all().
+#[derive(Clone, Debug)]
+pub struct IndexOfAll<'a, P> {
+    matches: MatchIndicesChars<'a, P>,
+}
+
+impl<P: CharPattern> Iterator for IndexOfAll<'_, P> {
+    type Item = usize;
+
+    fn next(&mut self) -> Option<usize> {
+        self.matches.next().map(|(index, _)| index)
+    }
+}
+
+impl<P: CharPattern> FusedIterator for IndexOfAll<'_, P> {}
+
+// -------------------------------------------------------------------------
+
+/// Get the character positions of all the matches of 'pattern' in a string,
+/// in one pass over the string. Index of the first character is 0.
+/// The search 'pattern' can be a string, a character, a set of characters or
+/// a function (see CharPattern).
+/// overlapping = false: continue searching after a match.
+/// overlapping = true: continue searching at the next character after the
+/// start of a match.
+/// An empty search string has no matches (same as indexof).
+/// Examples:
+///   indexof_all("aaaa", "aa", false).collect() => [0, 2]
+///   indexof_all("aaaa", "aa", true).collect()  => [0, 1, 2]
+///   indexof_all("a1b2", char::is_numeric, false).collect()  => [1, 3]
+pub fn indexof_all<P: CharPattern>(s: &str, pattern: P, overlapping: bool) -> IndexOfAll<'_, P> {
+    IndexOfAll {
+        matches: match_indices_chars(s, pattern, overlapping),
+    }
+}
+
+/// Same as indexof_all, but returns the character index and the byte index of
+/// each match: (character index, byte index).
+/// Example:
+///   match_indices_chars("é-é", "é", false).collect() => [(0, 0), (2, 3)]
+pub fn match_indices_chars<P: CharPattern>(s: &str, pattern: P, overlapping: bool) -> MatchIndicesChars<'_, P> {
+    MatchIndicesChars {
+        s,
+        pattern,
+        overlapping,
+        pos_byte: 0,
+        pos: 0,
+        done: false,
+    }
+}
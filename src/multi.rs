@@ -0,0 +1,220 @@
+/// MultiIndexOf: search many strings at once (Aho-Corasick automaton).
+///
+/// The automaton is built once from a list of search strings (needles). A
+/// search finds all the matches of all the needles in one pass over the bytes
+/// of the string, the time doesn't depend on the number of needles.
+/// Positions and lengths are counted in characters, same as indexof.
+/// Example:
+///   let banned = MultiIndexOf::new(["bad", "worse", "è"]);
+///   banned.find_all("très bad")  => [MultiMatch { needle: 2, start: 2, length: 1 },
+///                                   MultiMatch { needle: 0, start: 5, length: 3 }]
+use std::collections::VecDeque;
+use std::iter::FusedIterator;
+
+/// A match of MultiIndexOf. Positions are counted in characters.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct MultiMatch {
+    pub needle: usize, // Index of the needle in the list of needles
+    pub start: usize,  // Character index of the first character of the match
+    pub length: usize, // Length of the match in characters
+}
+
+#[derive(Clone, Debug, Default)]
+struct State {
+    next: Vec<(u8, u32)>, // Transitions, sorted by byte
+    fail: u32,            // State of the longest proper suffix that is in the trie
+    matches: Vec<u32>,    // Needles that end in this state (longest first)
+}
+
+impl State {
+    fn goto(&self, b: u8) -> Option<u32> {
+        self.next
+            .binary_search_by_key(&b, |&(key, _)| key)
+            .ok()
+            .map(|i| self.next[i].1)
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct MultiIndexOf {
+    needles: Vec<String>,
+    needle_lengths: Vec<usize>, // Length of each needle in characters
+    max_length: usize,          // Length of the longest needle in characters
+    states: Vec<State>,         // State 0 is the root
+}
+
+impl MultiIndexOf {
+    /// Build the automaton from a list of needles. Empty needles never match
+    /// (same as indexof).
+    pub fn new<I, S>(needles: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: AsRef<str>,
+    {
+        let needles: Vec<String> = needles.into_iter().map(|n| n.as_ref().to_owned()).collect();
+        let needle_lengths: Vec<usize> = needles.iter().map(|n| n.chars().count()).collect();
+        let mut states: Vec<State> = vec![State::default()];
+
+        // Trie of the needles
+        for (id, needle) in needles.iter().enumerate() {
+            if needle.is_empty() {
+                continue;
+            }
+            let mut state: usize = 0;
+            for &b in needle.as_bytes() {
+                state = match states[state].goto(b) {
+                    Some(next) => next as usize,
+                    None => {
+                        let next: usize = states.len();
+                        states.push(State::default());
+                        let pos: usize = states[state].next.partition_point(|&(key, _)| key < b);
+                        states[state].next.insert(pos, (b, next as u32));
+                        next
+                    }
+                };
+            }
+            states[state].matches.push(id as u32);
+        }
+
+        // Failure links, breadth first
+        let mut queue: VecDeque<usize> = states[0].next.iter().map(|&(_, s)| s as usize).collect();
+        while let Some(state) = queue.pop_front() {
+            for i in 0..states[state].next.len() {
+                let (b, next) = states[state].next[i];
+                let next: usize = next as usize;
+
+                let mut fail: usize = states[state].fail as usize;
+                let fail_next: usize = loop {
+                    if let Some(target) = states[fail].goto(b) {
+                        break target as usize;
+                    }
+                    if fail == 0 {
+                        break 0;
+                    }
+                    fail = states[fail].fail as usize;
+                };
+
+                states[next].fail = fail_next as u32;
+                let inherited: Vec<u32> = states[fail_next].matches.clone();
+                states[next].matches.extend(inherited);
+                queue.push_back(next);
+            }
+        }
+
+        MultiIndexOf {
+            max_length: needle_lengths.iter().copied().max().unwrap_or(0),
+            needles,
+            needle_lengths,
+            states,
+        }
+    }
+
+    /// Number of needles.
+    pub fn needle_count(&self) -> usize {
+        self.needles.len()
+    }
+
+    /// The needle with index 'needle'.
+    pub fn needle(&self, needle: usize) -> Option<&str> {
+        self.needles.get(needle).map(String::as_str)
+    }
+
+    /// Iterator over all the matches of all the needles in 's', including
+    /// overlapping matches. The matches are ordered by their end position, for
+    /// the same end position the longest match first.
+    pub fn find_iter<'a, 'b>(&'a self, s: &'b str) -> MultiMatches<'a, 'b> {
+        MultiMatches {
+            automaton: self,
+            bytes: s.as_bytes(),
+            pos_byte: 0,
+            chars: 0,
+            state: 0,
+            match_pos: 0,
+        }
+    }
+
+    /// All the matches of all the needles in 's', including overlapping
+    /// matches. Sorted by start position, for the same start position by needle index.
+    pub fn find_all(&self, s: &str) -> Vec<MultiMatch> {
+        let mut result: Vec<MultiMatch> = self.find_iter(s).collect();
+        result.sort_by_key(|m| (m.start, m.needle));
+        result
+    }
+
+    /// Get the first match from the character index 'start_index', same as
+    /// indexof for multiple needles. Returns None if not found.
+    /// If more needles match at the same position, returns the longest.
+    pub fn indexof(&self, s: &str, start_index: usize) -> Option<MultiMatch> {
+        let (start_byte, _) = s.char_indices().nth(start_index)?;
+        let mut best: Option<MultiMatch> = None;
+
+        for m in self.find_iter(&s[start_byte..]) {
+            // No later match can start before the best match
+            if let Some(b) = best {
+                if m.start + m.length > b.start + self.max_length {
+                    break;
+                }
+            }
+            if best.is_none_or(|b| m.start < b.start || (m.start == b.start && m.length > b.length)) {
+                best = Some(m);
+            }
+        }
+
+        best.map(|m| MultiMatch {
+            start: start_index + m.start,
+            ..m
+        })
+    }
+}
+
+// -------------------------------------------------------------------------
+
+/// Iterator over the matches of MultiIndexOf. Created by MultiIndexOf::find_iter().
+#[derive(Clone, Debug)]
+pub struct MultiMatches<'a, 'b> {
+    automaton: &'a MultiIndexOf,
+    bytes: &'b [u8],
+    pos_byte: usize,  // Next byte
+    chars: usize,     // Characters up to pos_byte
+    state: usize,     // Current state
+    match_pos: usize, // Next match of the current state to return
+}
+
+impl Iterator for MultiMatches<'_, '_> {
+    type Item = MultiMatch;
+
+    fn next(&mut self) -> Option<MultiMatch> {
+        let states: &[State] = &self.automaton.states;
+
+        loop {
+            if let Some(&id) = states[self.state].matches.get(self.match_pos) {
+                self.match_pos += 1;
+                let length: usize = self.automaton.needle_lengths[id as usize];
+                return Some(MultiMatch {
+                    needle: id as usize,
+                    start: self.chars - length,
+                    length,
+                });
+            }
+
+            let &b = self.bytes.get(self.pos_byte)?;
+            self.pos_byte += 1;
+            if (b & 0xC0) != 0x80 {
+                self.chars += 1; // First byte of a character
+            }
+
+            self.state = loop {
+                if let Some(next) = states[self.state].goto(b) {
+                    break next as usize;
+                }
+                if self.state == 0 {
+                    break 0;
+                }
+                self.state = states[self.state].fail as usize;
+            };
+            self.match_pos = 0;
+        }
+    }
+}
+
+impl FusedIterator for MultiMatches<'_, '_> {}
@@ -0,0 +1,117 @@
+/// Padding to a number of characters or terminal columns.
+///
+/// The fill string is repeated and truncated to the needed length, as
+/// JavaScript padStart and padEnd, but counted in characters instead of
+/// UTF-16 code units. The string is returned unchanged if it is already as
+/// long as 'width' (it is never truncated), or if the fill string is empty.
+/// Examples:
+///   pad_left("5", 3, "0")        => "005"
+///   pad_left("abc", 10, "123")   => "1231231abc"
+///   pad_right("abc", 6, "é.")    => "abcé.é"
+///   pad_center("ab", 6, "*", CenterBias::Left) => "**ab**"
+use crate::width::{display_width, substr_width};
+
+/// Unit of the width of pad_with, wrap_with, justify_with and align_columns.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum WidthMode {
+    /// Characters (Unicode scalar values).
+    #[default]
+    Chars,
+    /// Terminal columns (display_width): wide characters 2, combining marks 0.
+    Columns,
+}
+
+/// Position of the string when the padding of pad_center can't be divided
+/// equally between both sides.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum CenterBias {
+    /// The string is closer to the left: the extra fill character is on the
+    /// right (same as format!("{:^width$}")).
+    #[default]
+    Left,
+    /// The string is closer to the right: the extra fill character is on the left.
+    Right,
+}
+
+/// Where pad_with adds the padding.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum PadSide {
+    Left,
+    Right,
+    Center(CenterBias),
+}
+
+// -------------------------------------------------------------------------
+
+/// Pad the start of a string with 'fill' to 'width' characters.
+/// Example:
+///   pad_left("42", 5, "0") => "00042"
+pub fn pad_left(s: &str, width: usize, fill: &str) -> String {
+    pad_with(s, width, fill, PadSide::Left, WidthMode::Chars)
+}
+
+/// Pad the end of a string with 'fill' to 'width' characters.
+/// Example:
+///   pad_right("Total", 10, ". ") => "Total. . ."
+pub fn pad_right(s: &str, width: usize, fill: &str) -> String {
+    pad_with(s, width, fill, PadSide::Right, WidthMode::Chars)
+}
+
+/// Pad both sides of a string with 'fill' to 'width' characters. Both sides
+/// start with the first character of 'fill'. 'bias' chooses the side of the
+/// extra fill character if the padding is odd.
+/// Examples:
+///   pad_center("abc", 8, "-", CenterBias::Left)  => "--abc---"
+///   pad_center("abc", 8, "-", CenterBias::Right) => "---abc--"
+pub fn pad_center(s: &str, width: usize, fill: &str, bias: CenterBias) -> String {
+    pad_with(s, width, fill, PadSide::Center(bias), WidthMode::Chars)
+}
+
+/// Pad a string to 'width' characters or terminal columns ('mode').
+/// In WidthMode::Columns, a wide fill character that doesn't fit completely is
+/// replaced by spaces, so the result is always exactly 'width' columns wide
+/// (unless the string is wider). A fill string without width (only zero-width
+/// characters) is the same as an empty fill string.
+/// Examples:
+///   pad_with("日本", 8, "-", PadSide::Left, WidthMode::Columns) => "----日本"
+///   pad_with("ab", 5, "日", PadSide::Right, WidthMode::Columns) => "ab日 "
+pub fn pad_with(s: &str, width: usize, fill: &str, side: PadSide, mode: WidthMode) -> String {
+    let fill_len: usize = measure(fill, mode);
+    let count: usize = width.saturating_sub(measure(s, mode));
+    if count == 0 || fill_len == 0 {
+        return s.to_owned();
+    }
+
+    let (left, right) = match side {
+        PadSide::Left => (count, 0),
+        PadSide::Right => (0, count),
+        PadSide::Center(CenterBias::Left) => (count / 2, count - count / 2),
+        PadSide::Center(CenterBias::Right) => (count - count / 2, count / 2),
+    };
+    let mut result: String = String::with_capacity(s.len() + (left + right) * fill.len() / fill_len + 1);
+    push_fill(&mut result, fill, fill_len, left, mode);
+    result.push_str(s);
+    push_fill(&mut result, fill, fill_len, right, mode);
+    result
+}
+
+/// Width of 's' in the unit of 'mode'.
+pub(crate) fn measure(s: &str, mode: WidthMode) -> usize {
+    match mode {
+        WidthMode::Chars => s.chars().count(),
+        WidthMode::Columns => display_width(s),
+    }
+}
+
+/// Add 'count' characters or columns of 'fill' (repeated and truncated).
+fn push_fill(result: &mut String, fill: &str, fill_len: usize, count: usize, mode: WidthMode) {
+    match mode {
+        WidthMode::Chars => result.extend(fill.chars().cycle().take(count)),
+        WidthMode::Columns => {
+            let pad: String = substr_width(&fill.repeat(count / fill_len + 1), 0, count);
+            let missing: usize = count - display_width(&pad);
+            result.push_str(&pad);
+            result.extend(std::iter::repeat_n(' ', missing));
+        }
+    }
+}
@@ -0,0 +1,148 @@
+/// CharPattern: what to search for with indexof, lastindexof, indexof_all,
+/// match_indices_chars and str_remove_matches.
+///
+/// Similar to the Pattern trait of the standard library (not stable), but the
+/// functions of this library return character positions.
+/// Implemented for:
+///   &str, &String, &&str, &Cow<str>, &Box<str> : the string (an empty string never matches)
+///   char : the character
+///   &[char], [char; N], &[char; N] : any of the characters
+///   FnMut(char) -> bool : a character for which the function returns true
+/// Examples:
+///   indexof("abc123", |c: char| c.is_ascii_digit(), 0)  => Some(3)
+///   indexof("a,b;c", [',', ';'], 2)                    => Some(3)
+use crate::floor_char_boundary;
+use std::borrow::Cow;
+
+pub trait CharPattern {
+    /// Byte range (start, end) of the first match in 'haystack'.
+    fn find_in(&mut self, haystack: &str) -> Option<(usize, usize)>;
+
+    /// Byte range (start, end) of the last match in 'haystack' that starts at
+    /// byte position 'start_max' or before. 'start_max' is on a character boundary.
+    fn rfind_in(&mut self, haystack: &str, start_max: usize) -> Option<(usize, usize)>;
+}
+
+impl CharPattern for &str {
+    fn find_in(&mut self, haystack: &str) -> Option<(usize, usize)> {
+        if self.is_empty() {
+            return None;
+        }
+        haystack.find(*self).map(|pos| (pos, pos + self.len()))
+    }
+
+    fn rfind_in(&mut self, haystack: &str, start_max: usize) -> Option<(usize, usize)> {
+        if self.is_empty() {
+            return None;
+        }
+        let end: usize = floor_char_boundary(haystack, start_max.saturating_add(self.len()));
+        haystack[..end].rfind(*self).map(|pos| (pos, pos + self.len()))
+    }
+}
+
+impl CharPattern for &String {
+    fn find_in(&mut self, haystack: &str) -> Option<(usize, usize)> {
+        self.as_str().find_in(haystack)
+    }
+
+    fn rfind_in(&mut self, haystack: &str, start_max: usize) -> Option<(usize, usize)> {
+        self.as_str().rfind_in(haystack, start_max)
+    }
+}
+
+impl CharPattern for &&str {
+    fn find_in(&mut self, haystack: &str) -> Option<(usize, usize)> {
+        let mut needle: &str = self;
+        needle.find_in(haystack)
+    }
+
+    fn rfind_in(&mut self, haystack: &str, start_max: usize) -> Option<(usize, usize)> {
+        let mut needle: &str = self;
+        needle.rfind_in(haystack, start_max)
+    }
+}
+
+impl CharPattern for &Cow<'_, str> {
+    fn find_in(&mut self, haystack: &str) -> Option<(usize, usize)> {
+        self.as_ref().find_in(haystack)
+    }
+
+    fn rfind_in(&mut self, haystack: &str, start_max: usize) -> Option<(usize, usize)> {
+        self.as_ref().rfind_in(haystack, start_max)
+    }
+}
+
+impl CharPattern for &Box<str> {
+    fn find_in(&mut self, haystack: &str) -> Option<(usize, usize)> {
+        self.as_ref().find_in(haystack)
+    }
+
+    fn rfind_in(&mut self, haystack: &str, start_max: usize) -> Option<(usize, usize)> {
+        self.as_ref().rfind_in(haystack, start_max)
+    }
+}
+
+impl CharPattern for char {
+    fn find_in(&mut self, haystack: &str) -> Option<(usize, usize)> {
+        let c: char = *self;
+        (|x: char| x == c).find_in(haystack)
+    }
+
+    fn rfind_in(&mut self, haystack: &str, start_max: usize) -> Option<(usize, usize)> {
+        let c: char = *self;
+        (|x: char| x == c).rfind_in(haystack, start_max)
+    }
+}
+
+impl CharPattern for &[char] {
+    fn find_in(&mut self, haystack: &str) -> Option<(usize, usize)> {
+        let chars: &[char] = self;
+        (|x: char| chars.contains(&x)).find_in(haystack)
+    }
+
+    fn rfind_in(&mut self, haystack: &str, start_max: usize) -> Option<(usize, usize)> {
+        let chars: &[char] = self;
+        (|x: char| chars.contains(&x)).rfind_in(haystack, start_max)
+    }
+}
+
+impl<const N: usize> CharPattern for [char; N] {
+    fn find_in(&mut self, haystack: &str) -> Option<(usize, usize)> {
+        (&self[..]).find_in(haystack)
+    }
+
+    fn rfind_in(&mut self, haystack: &str, start_max: usize) -> Option<(usize, usize)> {
+        (&self[..]).rfind_in(haystack, start_max)
+    }
+}
+
+impl<const N: usize> CharPattern for &[char; N] {
+    fn find_in(&mut self, haystack: &str) -> Option<(usize, usize)> {
+        (&self[..]).find_in(haystack)
+    }
+
+    fn rfind_in(&mut self, haystack: &str, start_max: usize) -> Option<(usize, usize)> {
+        (&self[..]).rfind_in(haystack, start_max)
+    }
+}
+
+impl<F: FnMut(char) -> bool> CharPattern for F {
+    fn find_in(&mut self, haystack: &str) -> Option<(usize, usize)> {
+        haystack
+            .char_indices()
+            .find(|&(_, c)| self(c))
+            .map(|(pos, c)| (pos, pos + c.len_utf8()))
+    }
+
+    fn rfind_in(&mut self, haystack: &str, start_max: usize) -> Option<(usize, usize)> {
+        let end: usize = match haystack[start_max..].chars().next() {
+            Some(c) => start_max + c.len_utf8(),
+            None => haystack.len(),
+        };
+        haystack[..end]
+            .char_indices()
+            .rev()
+            .find(|&(_, c)| self(c))
+            .map(|(pos, c)| (pos, pos + c.len_utf8()))
+    }
+}
@@ -0,0 +1,173 @@
+/// Position conversions between byte, character, UTF-16 and grapheme indexes.
+///
+/// Rust string slicing uses byte positions, the functions of this library use
+/// character indexes, JavaScript / Java / C# use UTF-16 code unit indexes and
+/// the grapheme module uses grapheme cluster indexes.
+/// A byte position inside a character (or a UTF-16 index inside a surrogate
+/// pair, a position inside a grapheme cluster) is handled by Snap:
+///   Snap::Exact    : not on a boundary, returns None
+///   Snap::Backward : the start of the character (grapheme) that contains it
+///   Snap::Forward  : the start of the next character (grapheme)
+/// The functions never panic. They return None for a position past the end.
+/// Examples:
+///   byte_to_char("éa", 1, Snap::Exact)    => None (inside "é")
+///   byte_to_char("éa", 1, Snap::Backward) => Some(0)
+///   byte_to_char("éa", 1, Snap::Forward)  => Some(1)
+///   char_to_byte("éa", 1)                  => Some(2)
+use crate::grapheme::boundaries;
+use std::cmp::Ordering;
+use std::iter::once;
+use std::ops::Range;
+
+/// What to do with a position that is not on a boundary.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Snap {
+    /// Return None.
+    Exact,
+    /// Move to the previous boundary.
+    Backward,
+    /// Move to the next boundary.
+    Forward,
+}
+
+/// (byte position, character index) of every character start and the end of the string.
+fn char_bounds(s: &str) -> impl Iterator<Item = (usize, usize)> + '_ {
+    s.char_indices()
+        .map(|(pos, _)| pos)
+        .chain(once(s.len()))
+        .enumerate()
+        .map(|(index, pos)| (pos, index))
+}
+
+/// (byte position, UTF-16 index) of every character start and the end of the string.
+fn utf16_bounds(s: &str) -> impl Iterator<Item = (usize, usize)> + '_ {
+    s.char_indices()
+        .map(Some)
+        .chain(once(None))
+        .scan(0, |utf16_index: &mut usize, item| match item {
+            Some((pos, c)) => {
+                let bound: (usize, usize) = (pos, *utf16_index);
+                *utf16_index += c.len_utf16();
+                Some(bound)
+            }
+            None => Some((s.len(), *utf16_index)),
+        })
+}
+
+/// (byte position, grapheme index) of every grapheme boundary.
+fn grapheme_bounds(s: &str) -> impl Iterator<Item = (usize, usize)> {
+    boundaries(s).into_iter().enumerate().map(|(index, pos)| (pos, index))
+}
+
+/// Convert 'value' with a list of (byte position, index) boundaries, sorted.
+/// 'from_byte' true: byte position to index, false: index to byte position.
+fn convert(bounds: impl Iterator<Item = (usize, usize)>, from_byte: bool, value: usize, snap: Snap) -> Option<usize> {
+    let mut previous: Option<usize> = None;
+
+    for (pos, index) in bounds {
+        let (key, result) = if from_byte { (pos, index) } else { (index, pos) };
+        match key.cmp(&value) {
+            Ordering::Less => previous = Some(result),
+            Ordering::Equal => return Some(result),
+            Ordering::Greater => {
+                return match snap {
+                    Snap::Exact => None,
+                    Snap::Backward => previous,
+                    Snap::Forward => Some(result),
+                }
+            }
+        }
+    }
+
+    None // Past the end of the string
+}
+
+/// Convert both ends of a range. None if the start is after the end.
+fn convert_range(range: Range<usize>, mut f: impl FnMut(usize) -> Option<usize>) -> Option<Range<usize>> {
+    if range.start > range.end {
+        return None;
+    }
+    Some(f(range.start)?..f(range.end)?)
+}
+
+// -------------------------------------------------------------------------
+
+/// Character index of byte position 'byte_index'.
+/// 'byte_index' can be the length of the string (returns the number of characters).
+pub fn byte_to_char(s: &str, byte_index: usize, snap: Snap) -> Option<usize> {
+    convert(char_bounds(s), true, byte_index, snap)
+}
+
+/// Byte position of character index 'char_index'.
+/// 'char_index' can be the number of characters (returns the length in bytes).
+pub fn char_to_byte(s: &str, char_index: usize) -> Option<usize> {
+    convert(char_bounds(s), false, char_index, Snap::Exact)
+}
+
+/// Character index range of a byte range. 'snap' is used for both ends.
+/// Example:
+///   byte_range_to_char_range("aéb", 1..3, Snap::Exact) => Some(1..2)
+pub fn byte_range_to_char_range(s: &str, range: Range<usize>, snap: Snap) -> Option<Range<usize>> {
+    convert_range(range, |pos| byte_to_char(s, pos, snap))
+}
+
+/// Byte range of a character index range.
+/// Example:
+///   char_range_to_byte_range("aéb", 1..2) => Some(1..3)
+///   &s[char_range_to_byte_range(s, 1..2)?] => "é"
+pub fn char_range_to_byte_range(s: &str, range: Range<usize>) -> Option<Range<usize>> {
+    convert_range(range, |index| char_to_byte(s, index))
+}
+
+// -------------------------------------------------------------------------
+
+/// UTF-16 code unit index of byte position 'byte_index'.
+pub fn byte_to_utf16(s: &str, byte_index: usize, snap: Snap) -> Option<usize> {
+    convert(utf16_bounds(s), true, byte_index, snap)
+}
+
+/// Byte position of UTF-16 code unit index 'utf16_index'. An index inside a
+/// surrogate pair is not on a boundary ('snap'). Same as utf16::utf16_to_byte
+/// with a Snap instead of an error.
+pub fn utf16_to_byte_snapped(s: &str, utf16_index: usize, snap: Snap) -> Option<usize> {
+    convert(utf16_bounds(s), false, utf16_index, snap)
+}
+
+/// UTF-16 code unit range of a byte range. 'snap' is used for both ends.
+pub fn byte_range_to_utf16_range(s: &str, range: Range<usize>, snap: Snap) -> Option<Range<usize>> {
+    convert_range(range, |pos| byte_to_utf16(s, pos, snap))
+}
+
+/// Byte range of a UTF-16 code unit range. 'snap' is used for both ends.
+/// Example:
+///   utf16_range_to_byte_range("a😀", 1..3, Snap::Exact) => Some(1..5)
+pub fn utf16_range_to_byte_range(s: &str, range: Range<usize>, snap: Snap) -> Option<Range<usize>> {
+    convert_range(range, |index| utf16_to_byte_snapped(s, index, snap))
+}
+
+// -------------------------------------------------------------------------
+
+/// Grapheme index of byte position 'byte_index'. A byte position inside a
+/// grapheme cluster is not on a boundary ('snap').
+/// Example:
+///   byte_to_grapheme("e\u{301}a", 1, Snap::Forward) => Some(1)
+pub fn byte_to_grapheme(s: &str, byte_index: usize, snap: Snap) -> Option<usize> {
+    convert(grapheme_bounds(s), true, byte_index, snap)
+}
+
+/// Byte position of grapheme index 'grapheme_index'.
+pub fn grapheme_to_byte(s: &str, grapheme_index: usize) -> Option<usize> {
+    convert(grapheme_bounds(s), false, grapheme_index, Snap::Exact)
+}
+
+/// Grapheme index range of a byte range. 'snap' is used for both ends.
+pub fn byte_range_to_grapheme_range(s: &str, range: Range<usize>, snap: Snap) -> Option<Range<usize>> {
+    let bounds: Vec<(usize, usize)> = grapheme_bounds(s).collect();
+    convert_range(range, |pos| convert(bounds.iter().copied(), true, pos, snap))
+}
+
+/// Byte range of a grapheme index range.
+pub fn grapheme_range_to_byte_range(s: &str, range: Range<usize>) -> Option<Range<usize>> {
+    let bounds: Vec<(usize, usize)> = grapheme_bounds(s).collect();
+    convert_range(range, |index| convert(bounds.iter().copied(), false, index, Snap::Exact))
+}
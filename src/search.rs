@@ -0,0 +1,93 @@
+/// Case insensitive and accent insensitive search.
+///
+/// Case folding: full Unicode case folding, "ß" matches "ss" and "SS".
+/// Accents: characters are decomposed (canonical decomposition) and the
+/// combining marks are removed, "É" matches "E".
+/// The folding tables are part of the library (Unicode 14.0.0).
+use crate::indexof;
+use crate::tables::folding::{is_combining_mark, BASE_CHARACTERS, CASE_FOLDING};
+
+/// Options for indexof_with.
+/// Default: exact match, same as indexof.
+/// Example:
+///   SearchOptions { ignore_case: true, ignore_accents: true }
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct SearchOptions {
+    pub ignore_case: bool,
+    pub ignore_accents: bool,
+}
+
+// -------------------------------------------------------------------------
+
+/// Get the character position from one string into another, ignoring case
+/// and/or accents. Start searching from character index 'start_index'.
+/// Returns None if not found. Index of the first character is 0.
+/// The position is the character index in the original string, also when
+/// folding changes the length: "Straße" has "STRASSE" at position 0.
+/// A match has to start and end on a character of 's': "s" is not found in "ß".
+/// Examples:
+///   let options = SearchOptions { ignore_case: true, ignore_accents: true };
+///   indexof_with("Bonjour Émile", "emile", 0, options)  => Some(8)
+///   indexof_with("Die Straße", "STRASSE", 0, options)   => Some(4)
+pub fn indexof_with(s: &str, searchstring: &str, start_index: usize, options: SearchOptions) -> Option<usize> {
+    if !options.ignore_case && !options.ignore_accents {
+        return indexof(s, searchstring, start_index);
+    }
+
+    let mut search: Vec<char> = Vec::with_capacity(searchstring.len());
+    for c in searchstring.chars() {
+        fold_char(c, options, &mut search);
+    }
+    if search.is_empty() {
+        return None;
+    }
+
+    // Folded characters of 's' and the character index in 's' of each of them
+    let mut folded: Vec<char> = Vec::with_capacity(s.len());
+    let mut indexes: Vec<usize> = Vec::with_capacity(s.len());
+    for (index, c) in (start_index..).zip(s.chars().skip(start_index)) {
+        fold_char(c, options, &mut folded);
+        indexes.resize(folded.len(), index);
+    }
+
+    // Is folded position 'pos' the start of a character of 's'
+    let is_start = |pos: usize| pos == 0 || pos == folded.len() || indexes[pos] != indexes[pos - 1];
+
+    (0..(folded.len() + 1).saturating_sub(search.len()))
+        .find(|&pos| {
+            is_start(pos) && folded[pos..pos + search.len()] == search[..] && is_start(pos + search.len())
+        })
+        .map(|pos| indexes[pos])
+}
+
+// -------------------------------------------------------------------------
+
+/// Add the folded characters of 'c' to 'result'.
+fn fold_char(c: char, options: SearchOptions, result: &mut Vec<char>) {
+    if !options.ignore_case {
+        push_base_char(c, options, result);
+        return;
+    }
+
+    match CASE_FOLDING.binary_search_by_key(&c, |&(key, _)| key) {
+        Ok(i) => CASE_FOLDING[i]
+            .1
+            .chars()
+            .for_each(|f| push_base_char(f, options, result)),
+        Err(_) => c.to_lowercase().for_each(|f| push_base_char(f, options, result)),
+    }
+}
+
+/// Add 'c' to 'result', without accents if ignore_accents is set.
+fn push_base_char(c: char, options: SearchOptions, result: &mut Vec<char>) {
+    if options.ignore_accents {
+        if is_combining_mark(c) {
+            return;
+        }
+        if let Ok(i) = BASE_CHARACTERS.binary_search_by_key(&c, |&(key, _)| key) {
+            result.push(BASE_CHARACTERS[i].1);
+            return;
+        }
+    }
+    result.push(c);
+}
@@ -0,0 +1,1508 @@
+// Case folding, base characters and combining marks, generated from the
+// Unicode 14.0.0 character database.
+
+use std::cmp::Ordering;
+
+/// Full case folding (CaseFolding.txt status C and F) of the characters where
+/// it differs from char::to_lowercase(). Example: "ß" => "ss".
+#[rustfmt::skip]
+pub(crate) const CASE_FOLDING: &[(char, &str)] = &[
+    ('\u{B5}', "\u{3BC}"),
+    ('\u{DF}', "ss"),
+    ('\u{149}', "\u{2BC}n"),
+    ('\u{17F}', "s"),
+    ('\u{1F0}', "j\u{30C}"),
+    ('\u{345}', "\u{3B9}"),
+    ('\u{390}', "\u{3B9}\u{308}\u{301}"),
+    ('\u{3B0}', "\u{3C5}\u{308}\u{301}"),
+    ('\u{3C2}', "\u{3C3}"),
+    ('\u{3D0}', "\u{3B2}"),
+    ('\u{3D1}', "\u{3B8}"),
+    ('\u{3D5}', "\u{3C6}"),
+    ('\u{3D6}', "\u{3C0}"),
+    ('\u{3F0}', "\u{3BA}"),
+    ('\u{3F1}', "\u{3C1}"),
+    ('\u{3F5}', "\u{3B5}"),
+    ('\u{587}', "\u{565}\u{582}"),
+    ('\u{13A0}', "\u{13A0}"),
+    ('\u{13A1}', "\u{13A1}"),
+    ('\u{13A2}', "\u{13A2}"),
+    ('\u{13A3}', "\u{13A3}"),
+    ('\u{13A4}', "\u{13A4}"),
+    ('\u{13A5}', "\u{13A5}"),
+    ('\u{13A6}', "\u{13A6}"),
+    ('\u{13A7}', "\u{13A7}"),
+    ('\u{13A8}', "\u{13A8}"),
+    ('\u{13A9}', "\u{13A9}"),
+    ('\u{13AA}', "\u{13AA}"),
+    ('\u{13AB}', "\u{13AB}"),
+    ('\u{13AC}', "\u{13AC}"),
+    ('\u{13AD}', "\u{13AD}"),
+    ('\u{13AE}', "\u{13AE}"),
+    ('\u{13AF}', "\u{13AF}"),
+    ('\u{13B0}', "\u{13B0}"),
+    ('\u{13B1}', "\u{13B1}"),
+    ('\u{13B2}', "\u{13B2}"),
+    ('\u{13B3}', "\u{13B3}"),
+    ('\u{13B4}', "\u{13B4}"),
+    ('\u{13B5}', "\u{13B5}"),
+    ('\u{13B6}', "\u{13B6}"),
+    ('\u{13B7}', "\u{13B7}"),
+    ('\u{13B8}', "\u{13B8}"),
+    ('\u{13B9}', "\u{13B9}"),
+    ('\u{13BA}', "\u{13BA}"),
+    ('\u{13BB}', "\u{13BB}"),
+    ('\u{13BC}', "\u{13BC}"),
+    ('\u{13BD}', "\u{13BD}"),
+    ('\u{13BE}', "\u{13BE}"),
+    ('\u{13BF}', "\u{13BF}"),
+    ('\u{13C0}', "\u{13C0}"),
+    ('\u{13C1}', "\u{13C1}"),
+    ('\u{13C2}', "\u{13C2}"),
+    ('\u{13C3}', "\u{13C3}"),
+    ('\u{13C4}', "\u{13C4}"),
+    ('\u{13C5}', "\u{13C5}"),
+    ('\u{13C6}', "\u{13C6}"),
+    ('\u{13C7}', "\u{13C7}"),
+    ('\u{13C8}', "\u{13C8}"),
+    ('\u{13C9}', "\u{13C9}"),
+    ('\u{13CA}', "\u{13CA}"),
+    ('\u{13CB}', "\u{13CB}"),
+    ('\u{13CC}', "\u{13CC}"),
+    ('\u{13CD}', "\u{13CD}"),
+    ('\u{13CE}', "\u{13CE}"),
+    ('\u{13CF}', "\u{13CF}"),
+    ('\u{13D0}', "\u{13D0}"),
+    ('\u{13D1}', "\u{13D1}"),
+    ('\u{13D2}', "\u{13D2}"),
+    ('\u{13D3}', "\u{13D3}"),
+    ('\u{13D4}', "\u{13D4}"),
+    ('\u{13D5}', "\u{13D5}"),
+    ('\u{13D6}', "\u{13D6}"),
+    ('\u{13D7}', "\u{13D7}"),
+    ('\u{13D8}', "\u{13D8}"),
+    ('\u{13D9}', "\u{13D9}"),
+    ('\u{13DA}', "\u{13DA}"),
+    ('\u{13DB}', "\u{13DB}"),
+    ('\u{13DC}', "\u{13DC}"),
+    ('\u{13DD}', "\u{13DD}"),
+    ('\u{13DE}', "\u{13DE}"),
+    ('\u{13DF}', "\u{13DF}"),
+    ('\u{13E0}', "\u{13E0}"),
+    ('\u{13E1}', "\u{13E1}"),
+    ('\u{13E2}', "\u{13E2}"),
+    ('\u{13E3}', "\u{13E3}"),
+    ('\u{13E4}', "\u{13E4}"),
+    ('\u{13E5}', "\u{13E5}"),
+    ('\u{13E6}', "\u{13E6}"),
+    ('\u{13E7}', "\u{13E7}"),
+    ('\u{13E8}', "\u{13E8}"),
+    ('\u{13E9}', "\u{13E9}"),
+    ('\u{13EA}', "\u{13EA}"),
+    ('\u{13EB}', "\u{13EB}"),
+    ('\u{13EC}', "\u{13EC}"),
+    ('\u{13ED}', "\u{13ED}"),
+    ('\u{13EE}', "\u{13EE}"),
+    ('\u{13EF}', "\u{13EF}"),
+    ('\u{13F0}', "\u{13F0}"),
+    ('\u{13F1}', "\u{13F1}"),
+    ('\u{13F2}', "\u{13F2}"),
+    ('\u{13F3}', "\u{13F3}"),
+    ('\u{13F4}', "\u{13F4}"),
+    ('\u{13F5}', "\u{13F5}"),
+    ('\u{13F8}', "\u{13F0}"),
+    ('\u{13F9}', "\u{13F1}"),
+    ('\u{13FA}', "\u{13F2}"),
+    ('\u{13FB}', "\u{13F3}"),
+    ('\u{13FC}', "\u{13F4}"),
+    ('\u{13FD}', "\u{13F5}"),
+    ('\u{1C80}', "\u{432}"),
+    ('\u{1C81}', "\u{434}"),
+    ('\u{1C82}', "\u{43E}"),
+    ('\u{1C83}', "\u{441}"),
+    ('\u{1C84}', "\u{442}"),
+    ('\u{1C85}', "\u{442}"),
+    ('\u{1C86}', "\u{44A}"),
+    ('\u{1C87}', "\u{463}"),
+    ('\u{1C88}', "\u{A64B}"),
+    ('\u{1E96}', "h\u{331}"),
+    ('\u{1E97}', "t\u{308}"),
+    ('\u{1E98}', "w\u{30A}"),
+    ('\u{1E99}', "y\u{30A}"),
+    ('\u{1E9A}', "a\u{2BE}"),
+    ('\u{1E9B}', "\u{1E61}"),
+    ('\u{1E9E}', "ss"),
+    ('\u{1F50}', "\u{3C5}\u{313}"),
+    ('\u{1F52}', "\u{3C5}\u{313}\u{300}"),
+    ('\u{1F54}', "\u{3C5}\u{313}\u{301}"),
+    ('\u{1F56}', "\u{3C5}\u{313}\u{342}"),
+    ('\u{1F80}', "\u{1F00}\u{3B9}"),
+    ('\u{1F81}', "\u{1F01}\u{3B9}"),
+    ('\u{1F82}', "\u{1F02}\u{3B9}"),
+    ('\u{1F83}', "\u{1F03}\u{3B9}"),
+    ('\u{1F84}', "\u{1F04}\u{3B9}"),
+    ('\u{1F85}', "\u{1F05}\u{3B9}"),
+    ('\u{1F86}', "\u{1F06}\u{3B9}"),
+    ('\u{1F87}', "\u{1F07}\u{3B9}"),
+    ('\u{1F88}', "\u{1F00}\u{3B9}"),
+    ('\u{1F89}', "\u{1F01}\u{3B9}"),
+    ('\u{1F8A}', "\u{1F02}\u{3B9}"),
+    ('\u{1F8B}', "\u{1F03}\u{3B9}"),
+    ('\u{1F8C}', "\u{1F04}\u{3B9}"),
+    ('\u{1F8D}', "\u{1F05}\u{3B9}"),
+    ('\u{1F8E}', "\u{1F06}\u{3B9}"),
+    ('\u{1F8F}', "\u{1F07}\u{3B9}"),
+    ('\u{1F90}', "\u{1F20}\u{3B9}"),
+    ('\u{1F91}', "\u{1F21}\u{3B9}"),
+    ('\u{1F92}', "\u{1F22}\u{3B9}"),
+    ('\u{1F93}', "\u{1F23}\u{3B9}"),
+    ('\u{1F94}', "\u{1F24}\u{3B9}"),
+    ('\u{1F95}', "\u{1F25}\u{3B9}"),
+    ('\u{1F96}', "\u{1F26}\u{3B9}"),
+    ('\u{1F97}', "\u{1F27}\u{3B9}"),
+    ('\u{1F98}', "\u{1F20}\u{3B9}"),
+    ('\u{1F99}', "\u{1F21}\u{3B9}"),
+    ('\u{1F9A}', "\u{1F22}\u{3B9}"),
+    ('\u{1F9B}', "\u{1F23}\u{3B9}"),
+    ('\u{1F9C}', "\u{1F24}\u{3B9}"),
+    ('\u{1F9D}', "\u{1F25}\u{3B9}"),
+    ('\u{1F9E}', "\u{1F26}\u{3B9}"),
+    ('\u{1F9F}', "\u{1F27}\u{3B9}"),
+    ('\u{1FA0}', "\u{1F60}\u{3B9}"),
+    ('\u{1FA1}', "\u{1F61}\u{3B9}"),
+    ('\u{1FA2}', "\u{1F62}\u{3B9}"),
+    ('\u{1FA3}', "\u{1F63}\u{3B9}"),
+    ('\u{1FA4}', "\u{1F64}\u{3B9}"),
+    ('\u{1FA5}', "\u{1F65}\u{3B9}"),
+    ('\u{1FA6}', "\u{1F66}\u{3B9}"),
+    ('\u{1FA7}', "\u{1F67}\u{3B9}"),
+    ('\u{1FA8}', "\u{1F60}\u{3B9}"),
+    ('\u{1FA9}', "\u{1F61}\u{3B9}"),
+    ('\u{1FAA}', "\u{1F62}\u{3B9}"),
+    ('\u{1FAB}', "\u{1F63}\u{3B9}"),
+    ('\u{1FAC}', "\u{1F64}\u{3B9}"),
+    ('\u{1FAD}', "\u{1F65}\u{3B9}"),
+    ('\u{1FAE}', "\u{1F66}\u{3B9}"),
+    ('\u{1FAF}', "\u{1F67}\u{3B9}"),
+    ('\u{1FB2}', "\u{1F70}\u{3B9}"),
+    ('\u{1FB3}', "\u{3B1}\u{3B9}"),
+    ('\u{1FB4}', "\u{3AC}\u{3B9}"),
+    ('\u{1FB6}', "\u{3B1}\u{342}"),
+    ('\u{1FB7}', "\u{3B1}\u{342}\u{3B9}"),
+    ('\u{1FBC}', "\u{3B1}\u{3B9}"),
+    ('\u{1FBE}', "\u{3B9}"),
+    ('\u{1FC2}', "\u{1F74}\u{3B9}"),
+    ('\u{1FC3}', "\u{3B7}\u{3B9}"),
+    ('\u{1FC4}', "\u{3AE}\u{3B9}"),
+    ('\u{1FC6}', "\u{3B7}\u{342}"),
+    ('\u{1FC7}', "\u{3B7}\u{342}\u{3B9}"),
+    ('\u{1FCC}', "\u{3B7}\u{3B9}"),
+    ('\u{1FD2}', "\u{3B9}\u{308}\u{300}"),
+    ('\u{1FD3}', "\u{3B9}\u{308}\u{301}"),
+    ('\u{1FD6}', "\u{3B9}\u{342}"),
+    ('\u{1FD7}', "\u{3B9}\u{308}\u{342}"),
+    ('\u{1FE2}', "\u{3C5}\u{308}\u{300}"),
+    ('\u{1FE3}', "\u{3C5}\u{308}\u{301}"),
+    ('\u{1FE4}', "\u{3C1}\u{313}"),
+    ('\u{1FE6}', "\u{3C5}\u{342}"),
+    ('\u{1FE7}', "\u{3C5}\u{308}\u{342}"),
+    ('\u{1FF2}', "\u{1F7C}\u{3B9}"),
+    ('\u{1FF3}', "\u{3C9}\u{3B9}"),
+    ('\u{1FF4}', "\u{3CE}\u{3B9}"),
+    ('\u{1FF6}', "\u{3C9}\u{342}"),
+    ('\u{1FF7}', "\u{3C9}\u{342}\u{3B9}"),
+    ('\u{1FFC}', "\u{3C9}\u{3B9}"),
+    ('\u{AB70}', "\u{13A0}"),
+    ('\u{AB71}', "\u{13A1}"),
+    ('\u{AB72}', "\u{13A2}"),
+    ('\u{AB73}', "\u{13A3}"),
+    ('\u{AB74}', "\u{13A4}"),
+    ('\u{AB75}', "\u{13A5}"),
+    ('\u{AB76}', "\u{13A6}"),
+    ('\u{AB77}', "\u{13A7}"),
+    ('\u{AB78}', "\u{13A8}"),
+    ('\u{AB79}', "\u{13A9}"),
+    ('\u{AB7A}', "\u{13AA}"),
+    ('\u{AB7B}', "\u{13AB}"),
+    ('\u{AB7C}', "\u{13AC}"),
+    ('\u{AB7D}', "\u{13AD}"),
+    ('\u{AB7E}', "\u{13AE}"),
+    ('\u{AB7F}', "\u{13AF}"),
+    ('\u{AB80}', "\u{13B0}"),
+    ('\u{AB81}', "\u{13B1}"),
+    ('\u{AB82}', "\u{13B2}"),
+    ('\u{AB83}', "\u{13B3}"),
+    ('\u{AB84}', "\u{13B4}"),
+    ('\u{AB85}', "\u{13B5}"),
+    ('\u{AB86}', "\u{13B6}"),
+    ('\u{AB87}', "\u{13B7}"),
+    ('\u{AB88}', "\u{13B8}"),
+    ('\u{AB89}', "\u{13B9}"),
+    ('\u{AB8A}', "\u{13BA}"),
+    ('\u{AB8B}', "\u{13BB}"),
+    ('\u{AB8C}', "\u{13BC}"),
+    ('\u{AB8D}', "\u{13BD}"),
+    ('\u{AB8E}', "\u{13BE}"),
+    ('\u{AB8F}', "\u{13BF}"),
+    ('\u{AB90}', "\u{13C0}"),
+    ('\u{AB91}', "\u{13C1}"),
+    ('\u{AB92}', "\u{13C2}"),
+    ('\u{AB93}', "\u{13C3}"),
+    ('\u{AB94}', "\u{13C4}"),
+    ('\u{AB95}', "\u{13C5}"),
+    ('\u{AB96}', "\u{13C6}"),
+    ('\u{AB97}', "\u{13C7}"),
+    ('\u{AB98}', "\u{13C8}"),
+    ('\u{AB99}', "\u{13C9}"),
+    ('\u{AB9A}', "\u{13CA}"),
+    ('\u{AB9B}', "\u{13CB}"),
+    ('\u{AB9C}', "\u{13CC}"),
+    ('\u{AB9D}', "\u{13CD}"),
+    ('\u{AB9E}', "\u{13CE}"),
+    ('\u{AB9F}', "\u{13CF}"),
+    ('\u{ABA0}', "\u{13D0}"),
+    ('\u{ABA1}', "\u{13D1}"),
+    ('\u{ABA2}', "\u{13D2}"),
+    ('\u{ABA3}', "\u{13D3}"),
+    ('\u{ABA4}', "\u{13D4}"),
+    ('\u{ABA5}', "\u{13D5}"),
+    ('\u{ABA6}', "\u{13D6}"),
+    ('\u{ABA7}', "\u{13D7}"),
+    ('\u{ABA8}', "\u{13D8}"),
+    ('\u{ABA9}', "\u{13D9}"),
+    ('\u{ABAA}', "\u{13DA}"),
+    ('\u{ABAB}', "\u{13DB}"),
+    ('\u{ABAC}', "\u{13DC}"),
+    ('\u{ABAD}', "\u{13DD}"),
+    ('\u{ABAE}', "\u{13DE}"),
+    ('\u{ABAF}', "\u{13DF}"),
+    ('\u{ABB0}', "\u{13E0}"),
+    ('\u{ABB1}', "\u{13E1}"),
+    ('\u{ABB2}', "\u{13E2}"),
+    ('\u{ABB3}', "\u{13E3}"),
+    ('\u{ABB4}', "\u{13E4}"),
+    ('\u{ABB5}', "\u{13E5}"),
+    ('\u{ABB6}', "\u{13E6}"),
+    ('\u{ABB7}', "\u{13E7}"),
+    ('\u{ABB8}', "\u{13E8}"),
+    ('\u{ABB9}', "\u{13E9}"),
+    ('\u{ABBA}', "\u{13EA}"),
+    ('\u{ABBB}', "\u{13EB}"),
+    ('\u{ABBC}', "\u{13EC}"),
+    ('\u{ABBD}', "\u{13ED}"),
+    ('\u{ABBE}', "\u{13EE}"),
+    ('\u{ABBF}', "\u{13EF}"),
+    ('\u{FB00}', "ff"),
+    ('\u{FB01}', "fi"),
+    ('\u{FB02}', "fl"),
+    ('\u{FB03}', "ffi"),
+    ('\u{FB04}', "ffl"),
+    ('\u{FB05}', "st"),
+    ('\u{FB06}', "st"),
+    ('\u{FB13}', "\u{574}\u{576}"),
+    ('\u{FB14}', "\u{574}\u{565}"),
+    ('\u{FB15}', "\u{574}\u{56B}"),
+    ('\u{FB16}', "\u{57E}\u{576}"),
+    ('\u{FB17}', "\u{574}\u{56D}"),
+];
+
+/// Characters with a canonical decomposition that contains combining marks,
+/// and the base character without the combining marks. Example: "é" => "e".
+#[rustfmt::skip]
+pub(crate) const BASE_CHARACTERS: &[(char, char)] = &[
+    ('\u{C0}', '\u{41}'),
+    ('\u{C1}', '\u{41}'),
+    ('\u{C2}', '\u{41}'),
+    ('\u{C3}', '\u{41}'),
+    ('\u{C4}', '\u{41}'),
+    ('\u{C5}', '\u{41}'),
+    ('\u{C7}', '\u{43}'),
+    ('\u{C8}', '\u{45}'),
+    ('\u{C9}', '\u{45}'),
+    ('\u{CA}', '\u{45}'),
+    ('\u{CB}', '\u{45}'),
+    ('\u{CC}', '\u{49}'),
+    ('\u{CD}', '\u{49}'),
+    ('\u{CE}', '\u{49}'),
+    ('\u{CF}', '\u{49}'),
+    ('\u{D1}', '\u{4E}'),
+    ('\u{D2}', '\u{4F}'),
+    ('\u{D3}', '\u{4F}'),
+    ('\u{D4}', '\u{4F}'),
+    ('\u{D5}', '\u{4F}'),
+    ('\u{D6}', '\u{4F}'),
+    ('\u{D9}', '\u{55}'),
+    ('\u{DA}', '\u{55}'),
+    ('\u{DB}', '\u{55}'),
+    ('\u{DC}', '\u{55}'),
+    ('\u{DD}', '\u{59}'),
+    ('\u{E0}', '\u{61}'),
+    ('\u{E1}', '\u{61}'),
+    ('\u{E2}', '\u{61}'),
+    ('\u{E3}', '\u{61}'),
+    ('\u{E4}', '\u{61}'),
+    ('\u{E5}', '\u{61}'),
+    ('\u{E7}', '\u{63}'),
+    ('\u{E8}', '\u{65}'),
+    ('\u{E9}', '\u{65}'),
+    ('\u{EA}', '\u{65}'),
+    ('\u{EB}', '\u{65}'),
+    ('\u{EC}', '\u{69}'),
+    ('\u{ED}', '\u{69}'),
+    ('\u{EE}', '\u{69}'),
+    ('\u{EF}', '\u{69}'),
+    ('\u{F1}', '\u{6E}'),
+    ('\u{F2}', '\u{6F}'),
+    ('\u{F3}', '\u{6F}'),
+    ('\u{F4}', '\u{6F}'),
+    ('\u{F5}', '\u{6F}'),
+    ('\u{F6}', '\u{6F}'),
+    ('\u{F9}', '\u{75}'),
+    ('\u{FA}', '\u{75}'),
+    ('\u{FB}', '\u{75}'),
+    ('\u{FC}', '\u{75}'),
+    ('\u{FD}', '\u{79}'),
+    ('\u{FF}', '\u{79}'),
+    ('\u{100}', '\u{41}'),
+    ('\u{101}', '\u{61}'),
+    ('\u{102}', '\u{41}'),
+    ('\u{103}', '\u{61}'),
+    ('\u{104}', '\u{41}'),
+    ('\u{105}', '\u{61}'),
+    ('\u{106}', '\u{43}'),
+    ('\u{107}', '\u{63}'),
+    ('\u{108}', '\u{43}'),
+    ('\u{109}', '\u{63}'),
+    ('\u{10A}', '\u{43}'),
+    ('\u{10B}', '\u{63}'),
+    ('\u{10C}', '\u{43}'),
+    ('\u{10D}', '\u{63}'),
+    ('\u{10E}', '\u{44}'),
+    ('\u{10F}', '\u{64}'),
+    ('\u{112}', '\u{45}'),
+    ('\u{113}', '\u{65}'),
+    ('\u{114}', '\u{45}'),
+    ('\u{115}', '\u{65}'),
+    ('\u{116}', '\u{45}'),
+    ('\u{117}', '\u{65}'),
+    ('\u{118}', '\u{45}'),
+    ('\u{119}', '\u{65}'),
+    ('\u{11A}', '\u{45}'),
+    ('\u{11B}', '\u{65}'),
+    ('\u{11C}', '\u{47}'),
+    ('\u{11D}', '\u{67}'),
+    ('\u{11E}', '\u{47}'),
+    ('\u{11F}', '\u{67}'),
+    ('\u{120}', '\u{47}'),
+    ('\u{121}', '\u{67}'),
+    ('\u{122}', '\u{47}'),
+    ('\u{123}', '\u{67}'),
+    ('\u{124}', '\u{48}'),
+    ('\u{125}', '\u{68}'),
+    ('\u{128}', '\u{49}'),
+    ('\u{129}', '\u{69}'),
+    ('\u{12A}', '\u{49}'),
+    ('\u{12B}', '\u{69}'),
+    ('\u{12C}', '\u{49}'),
+    ('\u{12D}', '\u{69}'),
+    ('\u{12E}', '\u{49}'),
+    ('\u{12F}', '\u{69}'),
+    ('\u{130}', '\u{49}'),
+    ('\u{134}', '\u{4A}'),
+    ('\u{135}', '\u{6A}'),
+    ('\u{136}', '\u{4B}'),
+    ('\u{137}', '\u{6B}'),
+    ('\u{139}', '\u{4C}'),
+    ('\u{13A}', '\u{6C}'),
+    ('\u{13B}', '\u{4C}'),
+    ('\u{13C}', '\u{6C}'),
+    ('\u{13D}', '\u{4C}'),
+    ('\u{13E}', '\u{6C}'),
+    ('\u{143}', '\u{4E}'),
+    ('\u{144}', '\u{6E}'),
+    ('\u{145}', '\u{4E}'),
+    ('\u{146}', '\u{6E}'),
+    ('\u{147}', '\u{4E}'),
+    ('\u{148}', '\u{6E}'),
+    ('\u{14C}', '\u{4F}'),
+    ('\u{14D}', '\u{6F}'),
+    ('\u{14E}', '\u{4F}'),
+    ('\u{14F}', '\u{6F}'),
+    ('\u{150}', '\u{4F}'),
+    ('\u{151}', '\u{6F}'),
+    ('\u{154}', '\u{52}'),
+    ('\u{155}', '\u{72}'),
+    ('\u{156}', '\u{52}'),
+    ('\u{157}', '\u{72}'),
+    ('\u{158}', '\u{52}'),
+    ('\u{159}', '\u{72}'),
+    ('\u{15A}', '\u{53}'),
+    ('\u{15B}', '\u{73}'),
+    ('\u{15C}', '\u{53}'),
+    ('\u{15D}', '\u{73}'),
+    ('\u{15E}', '\u{53}'),
+    ('\u{15F}', '\u{73}'),
+    ('\u{160}', '\u{53}'),
+    ('\u{161}', '\u{73}'),
+    ('\u{162}', '\u{54}'),
+    ('\u{163}', '\u{74}'),
+    ('\u{164}', '\u{54}'),
+    ('\u{165}', '\u{74}'),
+    ('\u{168}', '\u{55}'),
+    ('\u{169}', '\u{75}'),
+    ('\u{16A}', '\u{55}'),
+    ('\u{16B}', '\u{75}'),
+    ('\u{16C}', '\u{55}'),
+    ('\u{16D}', '\u{75}'),
+    ('\u{16E}', '\u{55}'),
+    ('\u{16F}', '\u{75}'),
+    ('\u{170}', '\u{55}'),
+    ('\u{171}', '\u{75}'),
+    ('\u{172}', '\u{55}'),
+    ('\u{173}', '\u{75}'),
+    ('\u{174}', '\u{57}'),
+    ('\u{175}', '\u{77}'),
+    ('\u{176}', '\u{59}'),
+    ('\u{177}', '\u{79}'),
+    ('\u{178}', '\u{59}'),
+    ('\u{179}', '\u{5A}'),
+    ('\u{17A}', '\u{7A}'),
+    ('\u{17B}', '\u{5A}'),
+    ('\u{17C}', '\u{7A}'),
+    ('\u{17D}', '\u{5A}'),
+    ('\u{17E}', '\u{7A}'),
+    ('\u{1A0}', '\u{4F}'),
+    ('\u{1A1}', '\u{6F}'),
+    ('\u{1AF}', '\u{55}'),
+    ('\u{1B0}', '\u{75}'),
+    ('\u{1CD}', '\u{41}'),
+    ('\u{1CE}', '\u{61}'),
+    ('\u{1CF}', '\u{49}'),
+    ('\u{1D0}', '\u{69}'),
+    ('\u{1D1}', '\u{4F}'),
+    ('\u{1D2}', '\u{6F}'),
+    ('\u{1D3}', '\u{55}'),
+    ('\u{1D4}', '\u{75}'),
+    ('\u{1D5}', '\u{55}'),
+    ('\u{1D6}', '\u{75}'),
+    ('\u{1D7}', '\u{55}'),
+    ('\u{1D8}', '\u{75}'),
+    ('\u{1D9}', '\u{55}'),
+    ('\u{1DA}', '\u{75}'),
+    ('\u{1DB}', '\u{55}'),
+    ('\u{1DC}', '\u{75}'),
+    ('\u{1DE}', '\u{41}'),
+    ('\u{1DF}', '\u{61}'),
+    ('\u{1E0}', '\u{41}'),
+    ('\u{1E1}', '\u{61}'),
+    ('\u{1E2}', '\u{C6}'),
+    ('\u{1E3}', '\u{E6}'),
+    ('\u{1E6}', '\u{47}'),
+    ('\u{1E7}', '\u{67}'),
+    ('\u{1E8}', '\u{4B}'),
+    ('\u{1E9}', '\u{6B}'),
+    ('\u{1EA}', '\u{4F}'),
+    ('\u{1EB}', '\u{6F}'),
+    ('\u{1EC}', '\u{4F}'),
+    ('\u{1ED}', '\u{6F}'),
+    ('\u{1EE}', '\u{1B7}'),
+    ('\u{1EF}', '\u{292}'),
+    ('\u{1F0}', '\u{6A}'),
+    ('\u{1F4}', '\u{47}'),
+    ('\u{1F5}', '\u{67}'),
+    ('\u{1F8}', '\u{4E}'),
+    ('\u{1F9}', '\u{6E}'),
+    ('\u{1FA}', '\u{41}'),
+    ('\u{1FB}', '\u{61}'),
+    ('\u{1FC}', '\u{C6}'),
+    ('\u{1FD}', '\u{E6}'),
+    ('\u{1FE}', '\u{D8}'),
+    ('\u{1FF}', '\u{F8}'),
+    ('\u{200}', '\u{41}'),
+    ('\u{201}', '\u{61}'),
+    ('\u{202}', '\u{41}'),
+    ('\u{203}', '\u{61}'),
+    ('\u{204}', '\u{45}'),
+    ('\u{205}', '\u{65}'),
+    ('\u{206}', '\u{45}'),
+    ('\u{207}', '\u{65}'),
+    ('\u{208}', '\u{49}'),
+    ('\u{209}', '\u{69}'),
+    ('\u{20A}', '\u{49}'),
+    ('\u{20B}', '\u{69}'),
+    ('\u{20C}', '\u{4F}'),
+    ('\u{20D}', '\u{6F}'),
+    ('\u{20E}', '\u{4F}'),
+    ('\u{20F}', '\u{6F}'),
+    ('\u{210}', '\u{52}'),
+    ('\u{211}', '\u{72}'),
+    ('\u{212}', '\u{52}'),
+    ('\u{213}', '\u{72}'),
+    ('\u{214}', '\u{55}'),
+    ('\u{215}', '\u{75}'),
+    ('\u{216}', '\u{55}'),
+    ('\u{217}', '\u{75}'),
+    ('\u{218}', '\u{53}'),
+    ('\u{219}', '\u{73}'),
+    ('\u{21A}', '\u{54}'),
+    ('\u{21B}', '\u{74}'),
+    ('\u{21E}', '\u{48}'),
+    ('\u{21F}', '\u{68}'),
+    ('\u{226}', '\u{41}'),
+    ('\u{227}', '\u{61}'),
+    ('\u{228}', '\u{45}'),
+    ('\u{229}', '\u{65}'),
+    ('\u{22A}', '\u{4F}'),
+    ('\u{22B}', '\u{6F}'),
+    ('\u{22C}', '\u{4F}'),
+    ('\u{22D}', '\u{6F}'),
+    ('\u{22E}', '\u{4F}'),
+    ('\u{22F}', '\u{6F}'),
+    ('\u{230}', '\u{4F}'),
+    ('\u{231}', '\u{6F}'),
+    ('\u{232}', '\u{59}'),
+    ('\u{233}', '\u{79}'),
+    ('\u{385}', '\u{A8}'),
+    ('\u{386}', '\u{391}'),
+    ('\u{388}', '\u{395}'),
+    ('\u{389}', '\u{397}'),
+    ('\u{38A}', '\u{399}'),
+    ('\u{38C}', '\u{39F}'),
+    ('\u{38E}', '\u{3A5}'),
+    ('\u{38F}', '\u{3A9}'),
+    ('\u{390}', '\u{3B9}'),
+    ('\u{3AA}', '\u{399}'),
+    ('\u{3AB}', '\u{3A5}'),
+    ('\u{3AC}', '\u{3B1}'),
+    ('\u{3AD}', '\u{3B5}'),
+    ('\u{3AE}', '\u{3B7}'),
+    ('\u{3AF}', '\u{3B9}'),
+    ('\u{3B0}', '\u{3C5}'),
+    ('\u{3CA}', '\u{3B9}'),
+    ('\u{3CB}', '\u{3C5}'),
+    ('\u{3CC}', '\u{3BF}'),
+    ('\u{3CD}', '\u{3C5}'),
+    ('\u{3CE}', '\u{3C9}'),
+    ('\u{3D3}', '\u{3D2}'),
+    ('\u{3D4}', '\u{3D2}'),
+    ('\u{400}', '\u{415}'),
+    ('\u{401}', '\u{415}'),
+    ('\u{403}', '\u{413}'),
+    ('\u{407}', '\u{406}'),
+    ('\u{40C}', '\u{41A}'),
+    ('\u{40D}', '\u{418}'),
+    ('\u{40E}', '\u{423}'),
+    ('\u{419}', '\u{418}'),
+    ('\u{439}', '\u{438}'),
+    ('\u{450}', '\u{435}'),
+    ('\u{451}', '\u{435}'),
+    ('\u{453}', '\u{433}'),
+    ('\u{457}', '\u{456}'),
+    ('\u{45C}', '\u{43A}'),
+    ('\u{45D}', '\u{438}'),
+    ('\u{45E}', '\u{443}'),
+    ('\u{476}', '\u{474}'),
+    ('\u{477}', '\u{475}'),
+    ('\u{4C1}', '\u{416}'),
+    ('\u{4C2}', '\u{436}'),
+    ('\u{4D0}', '\u{410}'),
+    ('\u{4D1}', '\u{430}'),
+    ('\u{4D2}', '\u{410}'),
+    ('\u{4D3}', '\u{430}'),
+    ('\u{4D6}', '\u{415}'),
+    ('\u{4D7}', '\u{435}'),
+    ('\u{4DA}', '\u{4D8}'),
+    ('\u{4DB}', '\u{4D9}'),
+    ('\u{4DC}', '\u{416}'),
+    ('\u{4DD}', '\u{436}'),
+    ('\u{4DE}', '\u{417}'),
+    ('\u{4DF}', '\u{437}'),
+    ('\u{4E2}', '\u{418}'),
+    ('\u{4E3}', '\u{438}'),
+    ('\u{4E4}', '\u{418}'),
+    ('\u{4E5}', '\u{438}'),
+    ('\u{4E6}', '\u{41E}'),
+    ('\u{4E7}', '\u{43E}'),
+    ('\u{4EA}', '\u{4E8}'),
+    ('\u{4EB}', '\u{4E9}'),
+    ('\u{4EC}', '\u{42D}'),
+    ('\u{4ED}', '\u{44D}'),
+    ('\u{4EE}', '\u{423}'),
+    ('\u{4EF}', '\u{443}'),
+    ('\u{4F0}', '\u{423}'),
+    ('\u{4F1}', '\u{443}'),
+    ('\u{4F2}', '\u{423}'),
+    ('\u{4F3}', '\u{443}'),
+    ('\u{4F4}', '\u{427}'),
+    ('\u{4F5}', '\u{447}'),
+    ('\u{4F8}', '\u{42B}'),
+    ('\u{4F9}', '\u{44B}'),
+    ('\u{622}', '\u{627}'),
+    ('\u{623}', '\u{627}'),
+    ('\u{624}', '\u{648}'),
+    ('\u{625}', '\u{627}'),
+    ('\u{626}', '\u{64A}'),
+    ('\u{6C0}', '\u{6D5}'),
+    ('\u{6C2}', '\u{6C1}'),
+    ('\u{6D3}', '\u{6D2}'),
+    ('\u{929}', '\u{928}'),
+    ('\u{931}', '\u{930}'),
+    ('\u{934}', '\u{933}'),
+    ('\u{958}', '\u{915}'),
+    ('\u{959}', '\u{916}'),
+    ('\u{95A}', '\u{917}'),
+    ('\u{95B}', '\u{91C}'),
+    ('\u{95C}', '\u{921}'),
+    ('\u{95D}', '\u{922}'),
+    ('\u{95E}', '\u{92B}'),
+    ('\u{95F}', '\u{92F}'),
+    ('\u{9DC}', '\u{9A1}'),
+    ('\u{9DD}', '\u{9A2}'),
+    ('\u{9DF}', '\u{9AF}'),
+    ('\u{A33}', '\u{A32}'),
+    ('\u{A36}', '\u{A38}'),
+    ('\u{A59}', '\u{A16}'),
+    ('\u{A5A}', '\u{A17}'),
+    ('\u{A5B}', '\u{A1C}'),
+    ('\u{A5E}', '\u{A2B}'),
+    ('\u{B5C}', '\u{B21}'),
+    ('\u{B5D}', '\u{B22}'),
+    ('\u{C48}', '\u{C46}'),
+    ('\u{DDA}', '\u{DD9}'),
+    ('\u{F76}', '\u{FB2}'),
+    ('\u{F78}', '\u{FB3}'),
+    ('\u{1E00}', '\u{41}'),
+    ('\u{1E01}', '\u{61}'),
+    ('\u{1E02}', '\u{42}'),
+    ('\u{1E03}', '\u{62}'),
+    ('\u{1E04}', '\u{42}'),
+    ('\u{1E05}', '\u{62}'),
+    ('\u{1E06}', '\u{42}'),
+    ('\u{1E07}', '\u{62}'),
+    ('\u{1E08}', '\u{43}'),
+    ('\u{1E09}', '\u{63}'),
+    ('\u{1E0A}', '\u{44}'),
+    ('\u{1E0B}', '\u{64}'),
+    ('\u{1E0C}', '\u{44}'),
+    ('\u{1E0D}', '\u{64}'),
+    ('\u{1E0E}', '\u{44}'),
+    ('\u{1E0F}', '\u{64}'),
+    ('\u{1E10}', '\u{44}'),
+    ('\u{1E11}', '\u{64}'),
+    ('\u{1E12}', '\u{44}'),
+    ('\u{1E13}', '\u{64}'),
+    ('\u{1E14}', '\u{45}'),
+    ('\u{1E15}', '\u{65}'),
+    ('\u{1E16}', '\u{45}'),
+    ('\u{1E17}', '\u{65}'),
+    ('\u{1E18}', '\u{45}'),
+    ('\u{1E19}', '\u{65}'),
+    ('\u{1E1A}', '\u{45}'),
+    ('\u{1E1B}', '\u{65}'),
+    ('\u{1E1C}', '\u{45}'),
+    ('\u{1E1D}', '\u{65}'),
+    ('\u{1E1E}', '\u{46}'),
+    ('\u{1E1F}', '\u{66}'),
+    ('\u{1E20}', '\u{47}'),
+    ('\u{1E21}', '\u{67}'),
+    ('\u{1E22}', '\u{48}'),
+    ('\u{1E23}', '\u{68}'),
+    ('\u{1E24}', '\u{48}'),
+    ('\u{1E25}', '\u{68}'),
+    ('\u{1E26}', '\u{48}'),
+    ('\u{1E27}', '\u{68}'),
+    ('\u{1E28}', '\u{48}'),
+    ('\u{1E29}', '\u{68}'),
+    ('\u{1E2A}', '\u{48}'),
+    ('\u{1E2B}', '\u{68}'),
+    ('\u{1E2C}', '\u{49}'),
+    ('\u{1E2D}', '\u{69}'),
+    ('\u{1E2E}', '\u{49}'),
+    ('\u{1E2F}', '\u{69}'),
+    ('\u{1E30}', '\u{4B}'),
+    ('\u{1E31}', '\u{6B}'),
+    ('\u{1E32}', '\u{4B}'),
+    ('\u{1E33}', '\u{6B}'),
+    ('\u{1E34}', '\u{4B}'),
+    ('\u{1E35}', '\u{6B}'),
+    ('\u{1E36}', '\u{4C}'),
+    ('\u{1E37}', '\u{6C}'),
+    ('\u{1E38}', '\u{4C}'),
+    ('\u{1E39}', '\u{6C}'),
+    ('\u{1E3A}', '\u{4C}'),
+    ('\u{1E3B}', '\u{6C}'),
+    ('\u{1E3C}', '\u{4C}'),
+    ('\u{1E3D}', '\u{6C}'),
+    ('\u{1E3E}', '\u{4D}'),
+    ('\u{1E3F}', '\u{6D}'),
+    ('\u{1E40}', '\u{4D}'),
+    ('\u{1E41}', '\u{6D}'),
+    ('\u{1E42}', '\u{4D}'),
+    ('\u{1E43}', '\u{6D}'),
+    ('\u{1E44}', '\u{4E}'),
+    ('\u{1E45}', '\u{6E}'),
+    ('\u{1E46}', '\u{4E}'),
+    ('\u{1E47}', '\u{6E}'),
+    ('\u{1E48}', '\u{4E}'),
+    ('\u{1E49}', '\u{6E}'),
+    ('\u{1E4A}', '\u{4E}'),
+    ('\u{1E4B}', '\u{6E}'),
+    ('\u{1E4C}', '\u{4F}'),
+    ('\u{1E4D}', '\u{6F}'),
+    ('\u{1E4E}', '\u{4F}'),
+    ('\u{1E4F}', '\u{6F}'),
+    ('\u{1E50}', '\u{4F}'),
+    ('\u{1E51}', '\u{6F}'),
+    ('\u{1E52}', '\u{4F}'),
+    ('\u{1E53}', '\u{6F}'),
+    ('\u{1E54}', '\u{50}'),
+    ('\u{1E55}', '\u{70}'),
+    ('\u{1E56}', '\u{50}'),
+    ('\u{1E57}', '\u{70}'),
+    ('\u{1E58}', '\u{52}'),
+    ('\u{1E59}', '\u{72}'),
+    ('\u{1E5A}', '\u{52}'),
+    ('\u{1E5B}', '\u{72}'),
+    ('\u{1E5C}', '\u{52}'),
+    ('\u{1E5D}', '\u{72}'),
+    ('\u{1E5E}', '\u{52}'),
+    ('\u{1E5F}', '\u{72}'),
+    ('\u{1E60}', '\u{53}'),
+    ('\u{1E61}', '\u{73}'),
+    ('\u{1E62}', '\u{53}'),
+    ('\u{1E63}', '\u{73}'),
+    ('\u{1E64}', '\u{53}'),
+    ('\u{1E65}', '\u{73}'),
+    ('\u{1E66}', '\u{53}'),
+    ('\u{1E67}', '\u{73}'),
+    ('\u{1E68}', '\u{53}'),
+    ('\u{1E69}', '\u{73}'),
+    ('\u{1E6A}', '\u{54}'),
+    ('\u{1E6B}', '\u{74}'),
+    ('\u{1E6C}', '\u{54}'),
+    ('\u{1E6D}', '\u{74}'),
+    ('\u{1E6E}', '\u{54}'),
+    ('\u{1E6F}', '\u{74}'),
+    ('\u{1E70}', '\u{54}'),
+    ('\u{1E71}', '\u{74}'),
+    ('\u{1E72}', '\u{55}'),
+    ('\u{1E73}', '\u{75}'),
+    ('\u{1E74}', '\u{55}'),
+    ('\u{1E75}', '\u{75}'),
+    ('\u{1E76}', '\u{55}'),
+    ('\u{1E77}', '\u{75}'),
+    ('\u{1E78}', '\u{55}'),
+    ('\u{1E79}', '\u{75}'),
+    ('\u{1E7A}', '\u{55}'),
+    ('\u{1E7B}', '\u{75}'),
+    ('\u{1E7C}', '\u{56}'),
+    ('\u{1E7D}', '\u{76}'),
+    ('\u{1E7E}', '\u{56}'),
+    ('\u{1E7F}', '\u{76}'),
+    ('\u{1E80}', '\u{57}'),
+    ('\u{1E81}', '\u{77}'),
+    ('\u{1E82}', '\u{57}'),
+    ('\u{1E83}', '\u{77}'),
+    ('\u{1E84}', '\u{57}'),
+    ('\u{1E85}', '\u{77}'),
+    ('\u{1E86}', '\u{57}'),
+    ('\u{1E87}', '\u{77}'),
+    ('\u{1E88}', '\u{57}'),
+    ('\u{1E89}', '\u{77}'),
+    ('\u{1E8A}', '\u{58}'),
+    ('\u{1E8B}', '\u{78}'),
+    ('\u{1E8C}', '\u{58}'),
+    ('\u{1E8D}', '\u{78}'),
+    ('\u{1E8E}', '\u{59}'),
+    ('\u{1E8F}', '\u{79}'),
+    ('\u{1E90}', '\u{5A}'),
+    ('\u{1E91}', '\u{7A}'),
+    ('\u{1E92}', '\u{5A}'),
+    ('\u{1E93}', '\u{7A}'),
+    ('\u{1E94}', '\u{5A}'),
+    ('\u{1E95}', '\u{7A}'),
+    ('\u{1E96}', '\u{68}'),
+    ('\u{1E97}', '\u{74}'),
+    ('\u{1E98}', '\u{77}'),
+    ('\u{1E99}', '\u{79}'),
+    ('\u{1E9B}', '\u{17F}'),
+    ('\u{1EA0}', '\u{41}'),
+    ('\u{1EA1}', '\u{61}'),
+    ('\u{1EA2}', '\u{41}'),
+    ('\u{1EA3}', '\u{61}'),
+    ('\u{1EA4}', '\u{41}'),
+    ('\u{1EA5}', '\u{61}'),
+    ('\u{1EA6}', '\u{41}'),
+    ('\u{1EA7}', '\u{61}'),
+    ('\u{1EA8}', '\u{41}'),
+    ('\u{1EA9}', '\u{61}'),
+    ('\u{1EAA}', '\u{41}'),
+    ('\u{1EAB}', '\u{61}'),
+    ('\u{1EAC}', '\u{41}'),
+    ('\u{1EAD}', '\u{61}'),
+    ('\u{1EAE}', '\u{41}'),
+    ('\u{1EAF}', '\u{61}'),
+    ('\u{1EB0}', '\u{41}'),
+    ('\u{1EB1}', '\u{61}'),
+    ('\u{1EB2}', '\u{41}'),
+    ('\u{1EB3}', '\u{61}'),
+    ('\u{1EB4}', '\u{41}'),
+    ('\u{1EB5}', '\u{61}'),
+    ('\u{1EB6}', '\u{41}'),
+    ('\u{1EB7}', '\u{61}'),
+    ('\u{1EB8}', '\u{45}'),
+    ('\u{1EB9}', '\u{65}'),
+    ('\u{1EBA}', '\u{45}'),
+    ('\u{1EBB}', '\u{65}'),
+    ('\u{1EBC}', '\u{45}'),
+    ('\u{1EBD}', '\u{65}'),
+    ('\u{1EBE}', '\u{45}'),
+    ('\u{1EBF}', '\u{65}'),
+    ('\u{1EC0}', '\u{45}'),
+    ('\u{1EC1}', '\u{65}'),
+    ('\u{1EC2}', '\u{45}'),
+    ('\u{1EC3}', '\u{65}'),
+    ('\u{1EC4}', '\u{45}'),
+    ('\u{1EC5}', '\u{65}'),
+    ('\u{1EC6}', '\u{45}'),
+    ('\u{1EC7}', '\u{65}'),
+    ('\u{1EC8}', '\u{49}'),
+    ('\u{1EC9}', '\u{69}'),
+    ('\u{1ECA}', '\u{49}'),
+    ('\u{1ECB}', '\u{69}'),
+    ('\u{1ECC}', '\u{4F}'),
+    ('\u{1ECD}', '\u{6F}'),
+    ('\u{1ECE}', '\u{4F}'),
+    ('\u{1ECF}', '\u{6F}'),
+    ('\u{1ED0}', '\u{4F}'),
+    ('\u{1ED1}', '\u{6F}'),
+    ('\u{1ED2}', '\u{4F}'),
+    ('\u{1ED3}', '\u{6F}'),
+    ('\u{1ED4}', '\u{4F}'),
+    ('\u{1ED5}', '\u{6F}'),
+    ('\u{1ED6}', '\u{4F}'),
+    ('\u{1ED7}', '\u{6F}'),
+    ('\u{1ED8}', '\u{4F}'),
+    ('\u{1ED9}', '\u{6F}'),
+    ('\u{1EDA}', '\u{4F}'),
+    ('\u{1EDB}', '\u{6F}'),
+    ('\u{1EDC}', '\u{4F}'),
+    ('\u{1EDD}', '\u{6F}'),
+    ('\u{1EDE}', '\u{4F}'),
+    ('\u{1EDF}', '\u{6F}'),
+    ('\u{1EE0}', '\u{4F}'),
+    ('\u{1EE1}', '\u{6F}'),
+    ('\u{1EE2}', '\u{4F}'),
+    ('\u{1EE3}', '\u{6F}'),
+    ('\u{1EE4}', '\u{55}'),
+    ('\u{1EE5}', '\u{75}'),
+    ('\u{1EE6}', '\u{55}'),
+    ('\u{1EE7}', '\u{75}'),
+    ('\u{1EE8}', '\u{55}'),
+    ('\u{1EE9}', '\u{75}'),
+    ('\u{1EEA}', '\u{55}'),
+    ('\u{1EEB}', '\u{75}'),
+    ('\u{1EEC}', '\u{55}'),
+    ('\u{1EED}', '\u{75}'),
+    ('\u{1EEE}', '\u{55}'),
+    ('\u{1EEF}', '\u{75}'),
+    ('\u{1EF0}', '\u{55}'),
+    ('\u{1EF1}', '\u{75}'),
+    ('\u{1EF2}', '\u{59}'),
+    ('\u{1EF3}', '\u{79}'),
+    ('\u{1EF4}', '\u{59}'),
+    ('\u{1EF5}', '\u{79}'),
+    ('\u{1EF6}', '\u{59}'),
+    ('\u{1EF7}', '\u{79}'),
+    ('\u{1EF8}', '\u{59}'),
+    ('\u{1EF9}', '\u{79}'),
+    ('\u{1F00}', '\u{3B1}'),
+    ('\u{1F01}', '\u{3B1}'),
+    ('\u{1F02}', '\u{3B1}'),
+    ('\u{1F03}', '\u{3B1}'),
+    ('\u{1F04}', '\u{3B1}'),
+    ('\u{1F05}', '\u{3B1}'),
+    ('\u{1F06}', '\u{3B1}'),
+    ('\u{1F07}', '\u{3B1}'),
+    ('\u{1F08}', '\u{391}'),
+    ('\u{1F09}', '\u{391}'),
+    ('\u{1F0A}', '\u{391}'),
+    ('\u{1F0B}', '\u{391}'),
+    ('\u{1F0C}', '\u{391}'),
+    ('\u{1F0D}', '\u{391}'),
+    ('\u{1F0E}', '\u{391}'),
+    ('\u{1F0F}', '\u{391}'),
+    ('\u{1F10}', '\u{3B5}'),
+    ('\u{1F11}', '\u{3B5}'),
+    ('\u{1F12}', '\u{3B5}'),
+    ('\u{1F13}', '\u{3B5}'),
+    ('\u{1F14}', '\u{3B5}'),
+    ('\u{1F15}', '\u{3B5}'),
+    ('\u{1F18}', '\u{395}'),
+    ('\u{1F19}', '\u{395}'),
+    ('\u{1F1A}', '\u{395}'),
+    ('\u{1F1B}', '\u{395}'),
+    ('\u{1F1C}', '\u{395}'),
+    ('\u{1F1D}', '\u{395}'),
+    ('\u{1F20}', '\u{3B7}'),
+    ('\u{1F21}', '\u{3B7}'),
+    ('\u{1F22}', '\u{3B7}'),
+    ('\u{1F23}', '\u{3B7}'),
+    ('\u{1F24}', '\u{3B7}'),
+    ('\u{1F25}', '\u{3B7}'),
+    ('\u{1F26}', '\u{3B7}'),
+    ('\u{1F27}', '\u{3B7}'),
+    ('\u{1F28}', '\u{397}'),
+    ('\u{1F29}', '\u{397}'),
+    ('\u{1F2A}', '\u{397}'),
+    ('\u{1F2B}', '\u{397}'),
+    ('\u{1F2C}', '\u{397}'),
+    ('\u{1F2D}', '\u{397}'),
+    ('\u{1F2E}', '\u{397}'),
+    ('\u{1F2F}', '\u{397}'),
+    ('\u{1F30}', '\u{3B9}'),
+    ('\u{1F31}', '\u{3B9}'),
+    ('\u{1F32}', '\u{3B9}'),
+    ('\u{1F33}', '\u{3B9}'),
+    ('\u{1F34}', '\u{3B9}'),
+    ('\u{1F35}', '\u{3B9}'),
+    ('\u{1F36}', '\u{3B9}'),
+    ('\u{1F37}', '\u{3B9}'),
+    ('\u{1F38}', '\u{399}'),
+    ('\u{1F39}', '\u{399}'),
+    ('\u{1F3A}', '\u{399}'),
+    ('\u{1F3B}', '\u{399}'),
+    ('\u{1F3C}', '\u{399}'),
+    ('\u{1F3D}', '\u{399}'),
+    ('\u{1F3E}', '\u{399}'),
+    ('\u{1F3F}', '\u{399}'),
+    ('\u{1F40}', '\u{3BF}'),
+    ('\u{1F41}', '\u{3BF}'),
+    ('\u{1F42}', '\u{3BF}'),
+    ('\u{1F43}', '\u{3BF}'),
+    ('\u{1F44}', '\u{3BF}'),
+    ('\u{1F45}', '\u{3BF}'),
+    ('\u{1F48}', '\u{39F}'),
+    ('\u{1F49}', '\u{39F}'),
+    ('\u{1F4A}', '\u{39F}'),
+    ('\u{1F4B}', '\u{39F}'),
+    ('\u{1F4C}', '\u{39F}'),
+    ('\u{1F4D}', '\u{39F}'),
+    ('\u{1F50}', '\u{3C5}'),
+    ('\u{1F51}', '\u{3C5}'),
+    ('\u{1F52}', '\u{3C5}'),
+    ('\u{1F53}', '\u{3C5}'),
+    ('\u{1F54}', '\u{3C5}'),
+    ('\u{1F55}', '\u{3C5}'),
+    ('\u{1F56}', '\u{3C5}'),
+    ('\u{1F57}', '\u{3C5}'),
+    ('\u{1F59}', '\u{3A5}'),
+    ('\u{1F5B}', '\u{3A5}'),
+    ('\u{1F5D}', '\u{3A5}'),
+    ('\u{1F5F}', '\u{3A5}'),
+    ('\u{1F60}', '\u{3C9}'),
+    ('\u{1F61}', '\u{3C9}'),
+    ('\u{1F62}', '\u{3C9}'),
+    ('\u{1F63}', '\u{3C9}'),
+    ('\u{1F64}', '\u{3C9}'),
+    ('\u{1F65}', '\u{3C9}'),
+    ('\u{1F66}', '\u{3C9}'),
+    ('\u{1F67}', '\u{3C9}'),
+    ('\u{1F68}', '\u{3A9}'),
+    ('\u{1F69}', '\u{3A9}'),
+    ('\u{1F6A}', '\u{3A9}'),
+    ('\u{1F6B}', '\u{3A9}'),
+    ('\u{1F6C}', '\u{3A9}'),
+    ('\u{1F6D}', '\u{3A9}'),
+    ('\u{1F6E}', '\u{3A9}'),
+    ('\u{1F6F}', '\u{3A9}'),
+    ('\u{1F70}', '\u{3B1}'),
+    ('\u{1F71}', '\u{3B1}'),
+    ('\u{1F72}', '\u{3B5}'),
+    ('\u{1F73}', '\u{3B5}'),
+    ('\u{1F74}', '\u{3B7}'),
+    ('\u{1F75}', '\u{3B7}'),
+    ('\u{1F76}', '\u{3B9}'),
+    ('\u{1F77}', '\u{3B9}'),
+    ('\u{1F78}', '\u{3BF}'),
+    ('\u{1F79}', '\u{3BF}'),
+    ('\u{1F7A}', '\u{3C5}'),
+    ('\u{1F7B}', '\u{3C5}'),
+    ('\u{1F7C}', '\u{3C9}'),
+    ('\u{1F7D}', '\u{3C9}'),
+    ('\u{1F80}', '\u{3B1}'),
+    ('\u{1F81}', '\u{3B1}'),
+    ('\u{1F82}', '\u{3B1}'),
+    ('\u{1F83}', '\u{3B1}'),
+    ('\u{1F84}', '\u{3B1}'),
+    ('\u{1F85}', '\u{3B1}'),
+    ('\u{1F86}', '\u{3B1}'),
+    ('\u{1F87}', '\u{3B1}'),
+    ('\u{1F88}', '\u{391}'),
+    ('\u{1F89}', '\u{391}'),
+    ('\u{1F8A}', '\u{391}'),
+    ('\u{1F8B}', '\u{391}'),
+    ('\u{1F8C}', '\u{391}'),
+    ('\u{1F8D}', '\u{391}'),
+    ('\u{1F8E}', '\u{391}'),
+    ('\u{1F8F}', '\u{391}'),
+    ('\u{1F90}', '\u{3B7}'),
+    ('\u{1F91}', '\u{3B7}'),
+    ('\u{1F92}', '\u{3B7}'),
+    ('\u{1F93}', '\u{3B7}'),
+    ('\u{1F94}', '\u{3B7}'),
+    ('\u{1F95}', '\u{3B7}'),
+    ('\u{1F96}', '\u{3B7}'),
+    ('\u{1F97}', '\u{3B7}'),
+    ('\u{1F98}', '\u{397}'),
+    ('\u{1F99}', '\u{397}'),
+    ('\u{1F9A}', '\u{397}'),
+    ('\u{1F9B}', '\u{397}'),
+    ('\u{1F9C}', '\u{397}'),
+    ('\u{1F9D}', '\u{397}'),
+    ('\u{1F9E}', '\u{397}'),
+    ('\u{1F9F}', '\u{397}'),
+    ('\u{1FA0}', '\u{3C9}'),
+    ('\u{1FA1}', '\u{3C9}'),
+    ('\u{1FA2}', '\u{3C9}'),
+    ('\u{1FA3}', '\u{3C9}'),
+    ('\u{1FA4}', '\u{3C9}'),
+    ('\u{1FA5}', '\u{3C9}'),
+    ('\u{1FA6}', '\u{3C9}'),
+    ('\u{1FA7}', '\u{3C9}'),
+    ('\u{1FA8}', '\u{3A9}'),
+    ('\u{1FA9}', '\u{3A9}'),
+    ('\u{1FAA}', '\u{3A9}'),
+    ('\u{1FAB}', '\u{3A9}'),
+    ('\u{1FAC}', '\u{3A9}'),
+    ('\u{1FAD}', '\u{3A9}'),
+    ('\u{1FAE}', '\u{3A9}'),
+    ('\u{1FAF}', '\u{3A9}'),
+    ('\u{1FB0}', '\u{3B1}'),
+    ('\u{1FB1}', '\u{3B1}'),
+    ('\u{1FB2}', '\u{3B1}'),
+    ('\u{1FB3}', '\u{3B1}'),
+    ('\u{1FB4}', '\u{3B1}'),
+    ('\u{1FB6}', '\u{3B1}'),
+    ('\u{1FB7}', '\u{3B1}'),
+    ('\u{1FB8}', '\u{391}'),
+    ('\u{1FB9}', '\u{391}'),
+    ('\u{1FBA}', '\u{391}'),
+    ('\u{1FBB}', '\u{391}'),
+    ('\u{1FBC}', '\u{391}'),
+    ('\u{1FC1}', '\u{A8}'),
+    ('\u{1FC2}', '\u{3B7}'),
+    ('\u{1FC3}', '\u{3B7}'),
+    ('\u{1FC4}', '\u{3B7}'),
+    ('\u{1FC6}', '\u{3B7}'),
+    ('\u{1FC7}', '\u{3B7}'),
+    ('\u{1FC8}', '\u{395}'),
+    ('\u{1FC9}', '\u{395}'),
+    ('\u{1FCA}', '\u{397}'),
+    ('\u{1FCB}', '\u{397}'),
+    ('\u{1FCC}', '\u{397}'),
+    ('\u{1FCD}', '\u{1FBF}'),
+    ('\u{1FCE}', '\u{1FBF}'),
+    ('\u{1FCF}', '\u{1FBF}'),
+    ('\u{1FD0}', '\u{3B9}'),
+    ('\u{1FD1}', '\u{3B9}'),
+    ('\u{1FD2}', '\u{3B9}'),
+    ('\u{1FD3}', '\u{3B9}'),
+    ('\u{1FD6}', '\u{3B9}'),
+    ('\u{1FD7}', '\u{3B9}'),
+    ('\u{1FD8}', '\u{399}'),
+    ('\u{1FD9}', '\u{399}'),
+    ('\u{1FDA}', '\u{399}'),
+    ('\u{1FDB}', '\u{399}'),
+    ('\u{1FDD}', '\u{1FFE}'),
+    ('\u{1FDE}', '\u{1FFE}'),
+    ('\u{1FDF}', '\u{1FFE}'),
+    ('\u{1FE0}', '\u{3C5}'),
+    ('\u{1FE1}', '\u{3C5}'),
+    ('\u{1FE2}', '\u{3C5}'),
+    ('\u{1FE3}', '\u{3C5}'),
+    ('\u{1FE4}', '\u{3C1}'),
+    ('\u{1FE5}', '\u{3C1}'),
+    ('\u{1FE6}', '\u{3C5}'),
+    ('\u{1FE7}', '\u{3C5}'),
+    ('\u{1FE8}', '\u{3A5}'),
+    ('\u{1FE9}', '\u{3A5}'),
+    ('\u{1FEA}', '\u{3A5}'),
+    ('\u{1FEB}', '\u{3A5}'),
+    ('\u{1FEC}', '\u{3A1}'),
+    ('\u{1FED}', '\u{A8}'),
+    ('\u{1FEE}', '\u{A8}'),
+    ('\u{1FF2}', '\u{3C9}'),
+    ('\u{1FF3}', '\u{3C9}'),
+    ('\u{1FF4}', '\u{3C9}'),
+    ('\u{1FF6}', '\u{3C9}'),
+    ('\u{1FF7}', '\u{3C9}'),
+    ('\u{1FF8}', '\u{39F}'),
+    ('\u{1FF9}', '\u{39F}'),
+    ('\u{1FFA}', '\u{3A9}'),
+    ('\u{1FFB}', '\u{3A9}'),
+    ('\u{1FFC}', '\u{3A9}'),
+    ('\u{212B}', '\u{41}'),
+    ('\u{219A}', '\u{2190}'),
+    ('\u{219B}', '\u{2192}'),
+    ('\u{21AE}', '\u{2194}'),
+    ('\u{21CD}', '\u{21D0}'),
+    ('\u{21CE}', '\u{21D4}'),
+    ('\u{21CF}', '\u{21D2}'),
+    ('\u{2204}', '\u{2203}'),
+    ('\u{2209}', '\u{2208}'),
+    ('\u{220C}', '\u{220B}'),
+    ('\u{2224}', '\u{2223}'),
+    ('\u{2226}', '\u{2225}'),
+    ('\u{2241}', '\u{223C}'),
+    ('\u{2244}', '\u{2243}'),
+    ('\u{2247}', '\u{2245}'),
+    ('\u{2249}', '\u{2248}'),
+    ('\u{2260}', '\u{3D}'),
+    ('\u{2262}', '\u{2261}'),
+    ('\u{226D}', '\u{224D}'),
+    ('\u{226E}', '\u{3C}'),
+    ('\u{226F}', '\u{3E}'),
+    ('\u{2270}', '\u{2264}'),
+    ('\u{2271}', '\u{2265}'),
+    ('\u{2274}', '\u{2272}'),
+    ('\u{2275}', '\u{2273}'),
+    ('\u{2278}', '\u{2276}'),
+    ('\u{2279}', '\u{2277}'),
+    ('\u{2280}', '\u{227A}'),
+    ('\u{2281}', '\u{227B}'),
+    ('\u{2284}', '\u{2282}'),
+    ('\u{2285}', '\u{2283}'),
+    ('\u{2288}', '\u{2286}'),
+    ('\u{2289}', '\u{2287}'),
+    ('\u{22AC}', '\u{22A2}'),
+    ('\u{22AD}', '\u{22A8}'),
+    ('\u{22AE}', '\u{22A9}'),
+    ('\u{22AF}', '\u{22AB}'),
+    ('\u{22E0}', '\u{227C}'),
+    ('\u{22E1}', '\u{227D}'),
+    ('\u{22E2}', '\u{2291}'),
+    ('\u{22E3}', '\u{2292}'),
+    ('\u{22EA}', '\u{22B2}'),
+    ('\u{22EB}', '\u{22B3}'),
+    ('\u{22EC}', '\u{22B4}'),
+    ('\u{22ED}', '\u{22B5}'),
+    ('\u{2ADC}', '\u{2ADD}'),
+    ('\u{304C}', '\u{304B}'),
+    ('\u{304E}', '\u{304D}'),
+    ('\u{3050}', '\u{304F}'),
+    ('\u{3052}', '\u{3051}'),
+    ('\u{3054}', '\u{3053}'),
+    ('\u{3056}', '\u{3055}'),
+    ('\u{3058}', '\u{3057}'),
+    ('\u{305A}', '\u{3059}'),
+    ('\u{305C}', '\u{305B}'),
+    ('\u{305E}', '\u{305D}'),
+    ('\u{3060}', '\u{305F}'),
+    ('\u{3062}', '\u{3061}'),
+    ('\u{3065}', '\u{3064}'),
+    ('\u{3067}', '\u{3066}'),
+    ('\u{3069}', '\u{3068}'),
+    ('\u{3070}', '\u{306F}'),
+    ('\u{3071}', '\u{306F}'),
+    ('\u{3073}', '\u{3072}'),
+    ('\u{3074}', '\u{3072}'),
+    ('\u{3076}', '\u{3075}'),
+    ('\u{3077}', '\u{3075}'),
+    ('\u{3079}', '\u{3078}'),
+    ('\u{307A}', '\u{3078}'),
+    ('\u{307C}', '\u{307B}'),
+    ('\u{307D}', '\u{307B}'),
+    ('\u{3094}', '\u{3046}'),
+    ('\u{309E}', '\u{309D}'),
+    ('\u{30AC}', '\u{30AB}'),
+    ('\u{30AE}', '\u{30AD}'),
+    ('\u{30B0}', '\u{30AF}'),
+    ('\u{30B2}', '\u{30B1}'),
+    ('\u{30B4}', '\u{30B3}'),
+    ('\u{30B6}', '\u{30B5}'),
+    ('\u{30B8}', '\u{30B7}'),
+    ('\u{30BA}', '\u{30B9}'),
+    ('\u{30BC}', '\u{30BB}'),
+    ('\u{30BE}', '\u{30BD}'),
+    ('\u{30C0}', '\u{30BF}'),
+    ('\u{30C2}', '\u{30C1}'),
+    ('\u{30C5}', '\u{30C4}'),
+    ('\u{30C7}', '\u{30C6}'),
+    ('\u{30C9}', '\u{30C8}'),
+    ('\u{30D0}', '\u{30CF}'),
+    ('\u{30D1}', '\u{30CF}'),
+    ('\u{30D3}', '\u{30D2}'),
+    ('\u{30D4}', '\u{30D2}'),
+    ('\u{30D6}', '\u{30D5}'),
+    ('\u{30D7}', '\u{30D5}'),
+    ('\u{30D9}', '\u{30D8}'),
+    ('\u{30DA}', '\u{30D8}'),
+    ('\u{30DC}', '\u{30DB}'),
+    ('\u{30DD}', '\u{30DB}'),
+    ('\u{30F4}', '\u{30A6}'),
+    ('\u{30F7}', '\u{30EF}'),
+    ('\u{30F8}', '\u{30F0}'),
+    ('\u{30F9}', '\u{30F1}'),
+    ('\u{30FA}', '\u{30F2}'),
+    ('\u{30FE}', '\u{30FD}'),
+    ('\u{FB1D}', '\u{5D9}'),
+    ('\u{FB1F}', '\u{5F2}'),
+    ('\u{FB2A}', '\u{5E9}'),
+    ('\u{FB2B}', '\u{5E9}'),
+    ('\u{FB2C}', '\u{5E9}'),
+    ('\u{FB2D}', '\u{5E9}'),
+    ('\u{FB2E}', '\u{5D0}'),
+    ('\u{FB2F}', '\u{5D0}'),
+    ('\u{FB30}', '\u{5D0}'),
+    ('\u{FB31}', '\u{5D1}'),
+    ('\u{FB32}', '\u{5D2}'),
+    ('\u{FB33}', '\u{5D3}'),
+    ('\u{FB34}', '\u{5D4}'),
+    ('\u{FB35}', '\u{5D5}'),
+    ('\u{FB36}', '\u{5D6}'),
+    ('\u{FB38}', '\u{5D8}'),
+    ('\u{FB39}', '\u{5D9}'),
+    ('\u{FB3A}', '\u{5DA}'),
+    ('\u{FB3B}', '\u{5DB}'),
+    ('\u{FB3C}', '\u{5DC}'),
+    ('\u{FB3E}', '\u{5DE}'),
+    ('\u{FB40}', '\u{5E0}'),
+    ('\u{FB41}', '\u{5E1}'),
+    ('\u{FB43}', '\u{5E3}'),
+    ('\u{FB44}', '\u{5E4}'),
+    ('\u{FB46}', '\u{5E6}'),
+    ('\u{FB47}', '\u{5E7}'),
+    ('\u{FB48}', '\u{5E8}'),
+    ('\u{FB49}', '\u{5E9}'),
+    ('\u{FB4A}', '\u{5EA}'),
+    ('\u{FB4B}', '\u{5D5}'),
+    ('\u{FB4C}', '\u{5D1}'),
+    ('\u{FB4D}', '\u{5DB}'),
+    ('\u{FB4E}', '\u{5E4}'),
+    ('\u{1109A}', '\u{11099}'),
+    ('\u{1109C}', '\u{1109B}'),
+    ('\u{110AB}', '\u{110A5}'),
+    ('\u{1D15E}', '\u{1D157}'),
+    ('\u{1D15F}', '\u{1D158}'),
+    ('\u{1D160}', '\u{1D158}'),
+    ('\u{1D161}', '\u{1D158}'),
+    ('\u{1D162}', '\u{1D158}'),
+    ('\u{1D163}', '\u{1D158}'),
+    ('\u{1D164}', '\u{1D158}'),
+    ('\u{1D1BB}', '\u{1D1B9}'),
+    ('\u{1D1BC}', '\u{1D1BA}'),
+    ('\u{1D1BD}', '\u{1D1B9}'),
+    ('\u{1D1BE}', '\u{1D1BA}'),
+    ('\u{1D1BF}', '\u{1D1B9}'),
+    ('\u{1D1C0}', '\u{1D1BA}'),
+];
+
+/// Ranges of combining marks (canonical combining class not 0).
+#[rustfmt::skip]
+const COMBINING_MARKS: &[(char, char)] = &[
+    ('\u{300}', '\u{34E}'),
+    ('\u{350}', '\u{36F}'),
+    ('\u{483}', '\u{487}'),
+    ('\u{591}', '\u{5BD}'),
+    ('\u{5BF}', '\u{5BF}'),
+    ('\u{5C1}', '\u{5C2}'),
+    ('\u{5C4}', '\u{5C5}'),
+    ('\u{5C7}', '\u{5C7}'),
+    ('\u{610}', '\u{61A}'),
+    ('\u{64B}', '\u{65F}'),
+    ('\u{670}', '\u{670}'),
+    ('\u{6D6}', '\u{6DC}'),
+    ('\u{6DF}', '\u{6E4}'),
+    ('\u{6E7}', '\u{6E8}'),
+    ('\u{6EA}', '\u{6ED}'),
+    ('\u{711}', '\u{711}'),
+    ('\u{730}', '\u{74A}'),
+    ('\u{7EB}', '\u{7F3}'),
+    ('\u{7FD}', '\u{7FD}'),
+    ('\u{816}', '\u{819}'),
+    ('\u{81B}', '\u{823}'),
+    ('\u{825}', '\u{827}'),
+    ('\u{829}', '\u{82D}'),
+    ('\u{859}', '\u{85B}'),
+    ('\u{898}', '\u{89F}'),
+    ('\u{8CA}', '\u{8E1}'),
+    ('\u{8E3}', '\u{8FF}'),
+    ('\u{93C}', '\u{93C}'),
+    ('\u{94D}', '\u{94D}'),
+    ('\u{951}', '\u{954}'),
+    ('\u{9BC}', '\u{9BC}'),
+    ('\u{9CD}', '\u{9CD}'),
+    ('\u{9FE}', '\u{9FE}'),
+    ('\u{A3C}', '\u{A3C}'),
+    ('\u{A4D}', '\u{A4D}'),
+    ('\u{ABC}', '\u{ABC}'),
+    ('\u{ACD}', '\u{ACD}'),
+    ('\u{B3C}', '\u{B3C}'),
+    ('\u{B4D}', '\u{B4D}'),
+    ('\u{BCD}', '\u{BCD}'),
+    ('\u{C3C}', '\u{C3C}'),
+    ('\u{C4D}', '\u{C4D}'),
+    ('\u{C55}', '\u{C56}'),
+    ('\u{CBC}', '\u{CBC}'),
+    ('\u{CCD}', '\u{CCD}'),
+    ('\u{D3B}', '\u{D3C}'),
+    ('\u{D4D}', '\u{D4D}'),
+    ('\u{DCA}', '\u{DCA}'),
+    ('\u{E38}', '\u{E3A}'),
+    ('\u{E48}', '\u{E4B}'),
+    ('\u{EB8}', '\u{EBA}'),
+    ('\u{EC8}', '\u{ECB}'),
+    ('\u{F18}', '\u{F19}'),
+    ('\u{F35}', '\u{F35}'),
+    ('\u{F37}', '\u{F37}'),
+    ('\u{F39}', '\u{F39}'),
+    ('\u{F71}', '\u{F72}'),
+    ('\u{F74}', '\u{F74}'),
+    ('\u{F7A}', '\u{F7D}'),
+    ('\u{F80}', '\u{F80}'),
+    ('\u{F82}', '\u{F84}'),
+    ('\u{F86}', '\u{F87}'),
+    ('\u{FC6}', '\u{FC6}'),
+    ('\u{1037}', '\u{1037}'),
+    ('\u{1039}', '\u{103A}'),
+    ('\u{108D}', '\u{108D}'),
+    ('\u{135D}', '\u{135F}'),
+    ('\u{1714}', '\u{1715}'),
+    ('\u{1734}', '\u{1734}'),
+    ('\u{17D2}', '\u{17D2}'),
+    ('\u{17DD}', '\u{17DD}'),
+    ('\u{18A9}', '\u{18A9}'),
+    ('\u{1939}', '\u{193B}'),
+    ('\u{1A17}', '\u{1A18}'),
+    ('\u{1A60}', '\u{1A60}'),
+    ('\u{1A75}', '\u{1A7C}'),
+    ('\u{1A7F}', '\u{1A7F}'),
+    ('\u{1AB0}', '\u{1ABD}'),
+    ('\u{1ABF}', '\u{1ACE}'),
+    ('\u{1B34}', '\u{1B34}'),
+    ('\u{1B44}', '\u{1B44}'),
+    ('\u{1B6B}', '\u{1B73}'),
+    ('\u{1BAA}', '\u{1BAB}'),
+    ('\u{1BE6}', '\u{1BE6}'),
+    ('\u{1BF2}', '\u{1BF3}'),
+    ('\u{1C37}', '\u{1C37}'),
+    ('\u{1CD0}', '\u{1CD2}'),
+    ('\u{1CD4}', '\u{1CE0}'),
+    ('\u{1CE2}', '\u{1CE8}'),
+    ('\u{1CED}', '\u{1CED}'),
+    ('\u{1CF4}', '\u{1CF4}'),
+    ('\u{1CF8}', '\u{1CF9}'),
+    ('\u{1DC0}', '\u{1DFF}'),
+    ('\u{20D0}', '\u{20DC}'),
+    ('\u{20E1}', '\u{20E1}'),
+    ('\u{20E5}', '\u{20F0}'),
+    ('\u{2CEF}', '\u{2CF1}'),
+    ('\u{2D7F}', '\u{2D7F}'),
+    ('\u{2DE0}', '\u{2DFF}'),
+    ('\u{302A}', '\u{302F}'),
+    ('\u{3099}', '\u{309A}'),
+    ('\u{A66F}', '\u{A66F}'),
+    ('\u{A674}', '\u{A67D}'),
+    ('\u{A69E}', '\u{A69F}'),
+    ('\u{A6F0}', '\u{A6F1}'),
+    ('\u{A806}', '\u{A806}'),
+    ('\u{A82C}', '\u{A82C}'),
+    ('\u{A8C4}', '\u{A8C4}'),
+    ('\u{A8E0}', '\u{A8F1}'),
+    ('\u{A92B}', '\u{A92D}'),
+    ('\u{A953}', '\u{A953}'),
+    ('\u{A9B3}', '\u{A9B3}'),
+    ('\u{A9C0}', '\u{A9C0}'),
+    ('\u{AAB0}', '\u{AAB0}'),
+    ('\u{AAB2}', '\u{AAB4}'),
+    ('\u{AAB7}', '\u{AAB8}'),
+    ('\u{AABE}', '\u{AABF}'),
+    ('\u{AAC1}', '\u{AAC1}'),
+    ('\u{AAF6}', '\u{AAF6}'),
+    ('\u{ABED}', '\u{ABED}'),
+    ('\u{FB1E}', '\u{FB1E}'),
+    ('\u{FE20}', '\u{FE2F}'),
+    ('\u{101FD}', '\u{101FD}'),
+    ('\u{102E0}', '\u{102E0}'),
+    ('\u{10376}', '\u{1037A}'),
+    ('\u{10A0D}', '\u{10A0D}'),
+    ('\u{10A0F}', '\u{10A0F}'),
+    ('\u{10A38}', '\u{10A3A}'),
+    ('\u{10A3F}', '\u{10A3F}'),
+    ('\u{10AE5}', '\u{10AE6}'),
+    ('\u{10D24}', '\u{10D27}'),
+    ('\u{10EAB}', '\u{10EAC}'),
+    ('\u{10F46}', '\u{10F50}'),
+    ('\u{10F82}', '\u{10F85}'),
+    ('\u{11046}', '\u{11046}'),
+    ('\u{11070}', '\u{11070}'),
+    ('\u{1107F}', '\u{1107F}'),
+    ('\u{110B9}', '\u{110BA}'),
+    ('\u{11100}', '\u{11102}'),
+    ('\u{11133}', '\u{11134}'),
+    ('\u{11173}', '\u{11173}'),
+    ('\u{111C0}', '\u{111C0}'),
+    ('\u{111CA}', '\u{111CA}'),
+    ('\u{11235}', '\u{11236}'),
+    ('\u{112E9}', '\u{112EA}'),
+    ('\u{1133B}', '\u{1133C}'),
+    ('\u{1134D}', '\u{1134D}'),
+    ('\u{11366}', '\u{1136C}'),
+    ('\u{11370}', '\u{11374}'),
+    ('\u{11442}', '\u{11442}'),
+    ('\u{11446}', '\u{11446}'),
+    ('\u{1145E}', '\u{1145E}'),
+    ('\u{114C2}', '\u{114C3}'),
+    ('\u{115BF}', '\u{115C0}'),
+    ('\u{1163F}', '\u{1163F}'),
+    ('\u{116B6}', '\u{116B7}'),
+    ('\u{1172B}', '\u{1172B}'),
+    ('\u{11839}', '\u{1183A}'),
+    ('\u{1193D}', '\u{1193E}'),
+    ('\u{11943}', '\u{11943}'),
+    ('\u{119E0}', '\u{119E0}'),
+    ('\u{11A34}', '\u{11A34}'),
+    ('\u{11A47}', '\u{11A47}'),
+    ('\u{11A99}', '\u{11A99}'),
+    ('\u{11C3F}', '\u{11C3F}'),
+    ('\u{11D42}', '\u{11D42}'),
+    ('\u{11D44}', '\u{11D45}'),
+    ('\u{11D97}', '\u{11D97}'),
+    ('\u{16AF0}', '\u{16AF4}'),
+    ('\u{16B30}', '\u{16B36}'),
+    ('\u{16FF0}', '\u{16FF1}'),
+    ('\u{1BC9E}', '\u{1BC9E}'),
+    ('\u{1D165}', '\u{1D169}'),
+    ('\u{1D16D}', '\u{1D172}'),
+    ('\u{1D17B}', '\u{1D182}'),
+    ('\u{1D185}', '\u{1D18B}'),
+    ('\u{1D1AA}', '\u{1D1AD}'),
+    ('\u{1D242}', '\u{1D244}'),
+    ('\u{1E000}', '\u{1E006}'),
+    ('\u{1E008}', '\u{1E018}'),
+    ('\u{1E01B}', '\u{1E021}'),
+    ('\u{1E023}', '\u{1E024}'),
+    ('\u{1E026}', '\u{1E02A}'),
+    ('\u{1E130}', '\u{1E136}'),
+    ('\u{1E2AE}', '\u{1E2AE}'),
+    ('\u{1E2EC}', '\u{1E2EF}'),
+    ('\u{1E8D0}', '\u{1E8D6}'),
+    ('\u{1E944}', '\u{1E94A}'),
+];
+
+/// Is the character a combining mark (diacritic)?
+pub(crate) fn is_combining_mark(c: char) -> bool {
+    c >= '\u{300}'
+        && COMBINING_MARKS
+            .binary_search_by(|&(lo, hi)| {
+                if hi < c {
+                    Ordering::Less
+                } else if lo > c {
+                    Ordering::Greater
+                } else {
+                    Ordering::Equal
+                }
+            })
+            .is_ok()
+}
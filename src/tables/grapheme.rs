@@ -0,0 +1,699 @@
+// Grapheme_Cluster_Break property values (UAX #29) and Extended_Pictographic
+// (UTS #51), generated from the Unicode 14.0.0 character database.
+// Hangul syllables (LV and LVT) are not listed, they are calculated in
+// grapheme_category().
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(crate) enum Gcb {
+    Other,
+    Cr,
+    Lf,
+    Control,
+    Extend,
+    Zwj,
+    RegionalIndicator,
+    Prepend,
+    SpacingMark,
+    L,
+    V,
+    T,
+    Lv,
+    Lvt,
+    ExtendedPictographic,
+}
+
+/// Get the grapheme cluster break category of a character.
+pub(crate) fn grapheme_category(c: char) -> Gcb {
+    if c.is_ascii() {
+        return match c {
+            '\r' => Gcb::Cr,
+            '\n' => Gcb::Lf,
+            '\u{0}'..='\u{1F}' | '\u{7F}' => Gcb::Control,
+            _ => Gcb::Other,
+        };
+    }
+
+    // Precomposed Hangul syllables: every 28th syllable is LV, the others LVT
+    let cp: u32 = c as u32;
+    if (0xAC00..=0xD7A3).contains(&cp) {
+        return if (cp - 0xAC00).is_multiple_of(28) {
+            Gcb::Lv
+        } else {
+            Gcb::Lvt
+        };
+    }
+
+    match GRAPHEME_CATEGORIES.binary_search_by(|&(lo, hi, _)| {
+        if hi < c {
+            std::cmp::Ordering::Less
+        } else if lo > c {
+            std::cmp::Ordering::Greater
+        } else {
+            std::cmp::Ordering::Equal
+        }
+    }) {
+        Ok(i) => GRAPHEME_CATEGORIES[i].2,
+        Err(_) => Gcb::Other,
+    }
+}
+
+#[rustfmt::skip]
+const GRAPHEME_CATEGORIES: &[(char, char, Gcb)] = &[
+    ('\u{0}', '\u{9}', Gcb::Control),
+    ('\u{A}', '\u{A}', Gcb::Lf),
+    ('\u{B}', '\u{C}', Gcb::Control),
+    ('\u{D}', '\u{D}', Gcb::Cr),
+    ('\u{E}', '\u{1F}', Gcb::Control),
+    ('\u{7F}', '\u{9F}', Gcb::Control),
+    ('\u{A9}', '\u{A9}', Gcb::ExtendedPictographic),
+    ('\u{AD}', '\u{AD}', Gcb::Control),
+    ('\u{AE}', '\u{AE}', Gcb::ExtendedPictographic),
+    ('\u{300}', '\u{36F}', Gcb::Extend),
+    ('\u{483}', '\u{489}', Gcb::Extend),
+    ('\u{591}', '\u{5BD}', Gcb::Extend),
+    ('\u{5BF}', '\u{5BF}', Gcb::Extend),
+    ('\u{5C1}', '\u{5C2}', Gcb::Extend),
+    ('\u{5C4}', '\u{5C5}', Gcb::Extend),
+    ('\u{5C7}', '\u{5C7}', Gcb::Extend),
+    ('\u{600}', '\u{605}', Gcb::Prepend),
+    ('\u{610}', '\u{61A}', Gcb::Extend),
+    ('\u{61C}', '\u{61C}', Gcb::Control),
+    ('\u{64B}', '\u{65F}', Gcb::Extend),
+    ('\u{670}', '\u{670}', Gcb::Extend),
+    ('\u{6D6}', '\u{6DC}', Gcb::Extend),
+    ('\u{6DD}', '\u{6DD}', Gcb::Prepend),
+    ('\u{6DF}', '\u{6E4}', Gcb::Extend),
+    ('\u{6E7}', '\u{6E8}', Gcb::Extend),
+    ('\u{6EA}', '\u{6ED}', Gcb::Extend),
+    ('\u{70F}', '\u{70F}', Gcb::Prepend),
+    ('\u{711}', '\u{711}', Gcb::Extend),
+    ('\u{730}', '\u{74A}', Gcb::Extend),
+    ('\u{7A6}', '\u{7B0}', Gcb::Extend),
+    ('\u{7EB}', '\u{7F3}', Gcb::Extend),
+    ('\u{7FD}', '\u{7FD}', Gcb::Extend),
+    ('\u{816}', '\u{819}', Gcb::Extend),
+    ('\u{81B}', '\u{823}', Gcb::Extend),
+    ('\u{825}', '\u{827}', Gcb::Extend),
+    ('\u{829}', '\u{82D}', Gcb::Extend),
+    ('\u{859}', '\u{85B}', Gcb::Extend),
+    ('\u{890}', '\u{891}', Gcb::Prepend),
+    ('\u{898}', '\u{89F}', Gcb::Extend),
+    ('\u{8CA}', '\u{8E1}', Gcb::Extend),
+    ('\u{8E2}', '\u{8E2}', Gcb::Prepend),
+    ('\u{8E3}', '\u{902}', Gcb::Extend),
+    ('\u{903}', '\u{903}', Gcb::SpacingMark),
+    ('\u{93A}', '\u{93A}', Gcb::Extend),
+    ('\u{93B}', '\u{93B}', Gcb::SpacingMark),
+    ('\u{93C}', '\u{93C}', Gcb::Extend),
+    ('\u{93E}', '\u{940}', Gcb::SpacingMark),
+    ('\u{941}', '\u{948}', Gcb::Extend),
+    ('\u{949}', '\u{94C}', Gcb::SpacingMark),
+    ('\u{94D}', '\u{94D}', Gcb::Extend),
+    ('\u{94E}', '\u{94F}', Gcb::SpacingMark),
+    ('\u{951}', '\u{957}', Gcb::Extend),
+    ('\u{962}', '\u{963}', Gcb::Extend),
+    ('\u{981}', '\u{981}', Gcb::Extend),
+    ('\u{982}', '\u{983}', Gcb::SpacingMark),
+    ('\u{9BC}', '\u{9BC}', Gcb::Extend),
+    ('\u{9BE}', '\u{9BE}', Gcb::Extend),
+    ('\u{9BF}', '\u{9C0}', Gcb::SpacingMark),
+    ('\u{9C1}', '\u{9C4}', Gcb::Extend),
+    ('\u{9C7}', '\u{9C8}', Gcb::SpacingMark),
+    ('\u{9CB}', '\u{9CC}', Gcb::SpacingMark),
+    ('\u{9CD}', '\u{9CD}', Gcb::Extend),
+    ('\u{9D7}', '\u{9D7}', Gcb::Extend),
+    ('\u{9E2}', '\u{9E3}', Gcb::Extend),
+    ('\u{9FE}', '\u{9FE}', Gcb::Extend),
+    ('\u{A01}', '\u{A02}', Gcb::Extend),
+    ('\u{A03}', '\u{A03}', Gcb::SpacingMark),
+    ('\u{A3C}', '\u{A3C}', Gcb::Extend),
+    ('\u{A3E}', '\u{A40}', Gcb::SpacingMark),
+    ('\u{A41}', '\u{A42}', Gcb::Extend),
+    ('\u{A47}', '\u{A48}', Gcb::Extend),
+    ('\u{A4B}', '\u{A4D}', Gcb::Extend),
+    ('\u{A51}', '\u{A51}', Gcb::Extend),
+    ('\u{A70}', '\u{A71}', Gcb::Extend),
+    ('\u{A75}', '\u{A75}', Gcb::Extend),
+    ('\u{A81}', '\u{A82}', Gcb::Extend),
+    ('\u{A83}', '\u{A83}', Gcb::SpacingMark),
+    ('\u{ABC}', '\u{ABC}', Gcb::Extend),
+    ('\u{ABE}', '\u{AC0}', Gcb::SpacingMark),
+    ('\u{AC1}', '\u{AC5}', Gcb::Extend),
+    ('\u{AC7}', '\u{AC8}', Gcb::Extend),
+    ('\u{AC9}', '\u{AC9}', Gcb::SpacingMark),
+    ('\u{ACB}', '\u{ACC}', Gcb::SpacingMark),
+    ('\u{ACD}', '\u{ACD}', Gcb::Extend),
+    ('\u{AE2}', '\u{AE3}', Gcb::Extend),
+    ('\u{AFA}', '\u{AFF}', Gcb::Extend),
+    ('\u{B01}', '\u{B01}', Gcb::Extend),
+    ('\u{B02}', '\u{B03}', Gcb::SpacingMark),
+    ('\u{B3C}', '\u{B3C}', Gcb::Extend),
+    ('\u{B3E}', '\u{B3F}', Gcb::Extend),
+    ('\u{B40}', '\u{B40}', Gcb::SpacingMark),
+    ('\u{B41}', '\u{B44}', Gcb::Extend),
+    ('\u{B47}', '\u{B48}', Gcb::SpacingMark),
+    ('\u{B4B}', '\u{B4C}', Gcb::SpacingMark),
+    ('\u{B4D}', '\u{B4D}', Gcb::Extend),
+    ('\u{B55}', '\u{B57}', Gcb::Extend),
+    ('\u{B62}', '\u{B63}', Gcb::Extend),
+    ('\u{B82}', '\u{B82}', Gcb::Extend),
+    ('\u{BBE}', '\u{BBE}', Gcb::Extend),
+    ('\u{BBF}', '\u{BBF}', Gcb::SpacingMark),
+    ('\u{BC0}', '\u{BC0}', Gcb::Extend),
+    ('\u{BC1}', '\u{BC2}', Gcb::SpacingMark),
+    ('\u{BC6}', '\u{BC8}', Gcb::SpacingMark),
+    ('\u{BCA}', '\u{BCC}', Gcb::SpacingMark),
+    ('\u{BCD}', '\u{BCD}', Gcb::Extend),
+    ('\u{BD7}', '\u{BD7}', Gcb::Extend),
+    ('\u{C00}', '\u{C00}', Gcb::Extend),
+    ('\u{C01}', '\u{C03}', Gcb::SpacingMark),
+    ('\u{C04}', '\u{C04}', Gcb::Extend),
+    ('\u{C3C}', '\u{C3C}', Gcb::Extend),
+    ('\u{C3E}', '\u{C40}', Gcb::Extend),
+    ('\u{C41}', '\u{C44}', Gcb::SpacingMark),
+    ('\u{C46}', '\u{C48}', Gcb::Extend),
+    ('\u{C4A}', '\u{C4D}', Gcb::Extend),
+    ('\u{C55}', '\u{C56}', Gcb::Extend),
+    ('\u{C62}', '\u{C63}', Gcb::Extend),
+    ('\u{C81}', '\u{C81}', Gcb::Extend),
+    ('\u{C82}', '\u{C83}', Gcb::SpacingMark),
+    ('\u{CBC}', '\u{CBC}', Gcb::Extend),
+    ('\u{CBE}', '\u{CBE}', Gcb::SpacingMark),
+    ('\u{CBF}', '\u{CBF}', Gcb::Extend),
+    ('\u{CC0}', '\u{CC1}', Gcb::SpacingMark),
+    ('\u{CC2}', '\u{CC2}', Gcb::Extend),
+    ('\u{CC3}', '\u{CC4}', Gcb::SpacingMark),
+    ('\u{CC6}', '\u{CC6}', Gcb::Extend),
+    ('\u{CC7}', '\u{CC8}', Gcb::SpacingMark),
+    ('\u{CCA}', '\u{CCB}', Gcb::SpacingMark),
+    ('\u{CCC}', '\u{CCD}', Gcb::Extend),
+    ('\u{CD5}', '\u{CD6}', Gcb::Extend),
+    ('\u{CE2}', '\u{CE3}', Gcb::Extend),
+    ('\u{D00}', '\u{D01}', Gcb::Extend),
+    ('\u{D02}', '\u{D03}', Gcb::SpacingMark),
+    ('\u{D3B}', '\u{D3C}', Gcb::Extend),
+    ('\u{D3E}', '\u{D3E}', Gcb::Extend),
+    ('\u{D3F}', '\u{D40}', Gcb::SpacingMark),
+    ('\u{D41}', '\u{D44}', Gcb::Extend),
+    ('\u{D46}', '\u{D48}', Gcb::SpacingMark),
+    ('\u{D4A}', '\u{D4C}', Gcb::SpacingMark),
+    ('\u{D4D}', '\u{D4D}', Gcb::Extend),
+    ('\u{D4E}', '\u{D4E}', Gcb::Prepend),
+    ('\u{D57}', '\u{D57}', Gcb::Extend),
+    ('\u{D62}', '\u{D63}', Gcb::Extend),
+    ('\u{D81}', '\u{D81}', Gcb::Extend),
+    ('\u{D82}', '\u{D83}', Gcb::SpacingMark),
+    ('\u{DCA}', '\u{DCA}', Gcb::Extend),
+    ('\u{DCF}', '\u{DCF}', Gcb::Extend),
+    ('\u{DD0}', '\u{DD1}', Gcb::SpacingMark),
+    ('\u{DD2}', '\u{DD4}', Gcb::Extend),
+    ('\u{DD6}', '\u{DD6}', Gcb::Extend),
+    ('\u{DD8}', '\u{DDE}', Gcb::SpacingMark),
+    ('\u{DDF}', '\u{DDF}', Gcb::Extend),
+    ('\u{DF2}', '\u{DF3}', Gcb::SpacingMark),
+    ('\u{E31}', '\u{E31}', Gcb::Extend),
+    ('\u{E33}', '\u{E33}', Gcb::SpacingMark),
+    ('\u{E34}', '\u{E3A}', Gcb::Extend),
+    ('\u{E47}', '\u{E4E}', Gcb::Extend),
+    ('\u{EB1}', '\u{EB1}', Gcb::Extend),
+    ('\u{EB3}', '\u{EB3}', Gcb::SpacingMark),
+    ('\u{EB4}', '\u{EBC}', Gcb::Extend),
+    ('\u{EC8}', '\u{ECD}', Gcb::Extend),
+    ('\u{F18}', '\u{F19}', Gcb::Extend),
+    ('\u{F35}', '\u{F35}', Gcb::Extend),
+    ('\u{F37}', '\u{F37}', Gcb::Extend),
+    ('\u{F39}', '\u{F39}', Gcb::Extend),
+    ('\u{F3E}', '\u{F3F}', Gcb::SpacingMark),
+    ('\u{F71}', '\u{F7E}', Gcb::Extend),
+    ('\u{F7F}', '\u{F7F}', Gcb::SpacingMark),
+    ('\u{F80}', '\u{F84}', Gcb::Extend),
+    ('\u{F86}', '\u{F87}', Gcb::Extend),
+    ('\u{F8D}', '\u{F97}', Gcb::Extend),
+    ('\u{F99}', '\u{FBC}', Gcb::Extend),
+    ('\u{FC6}', '\u{FC6}', Gcb::Extend),
+    ('\u{102D}', '\u{1030}', Gcb::Extend),
+    ('\u{1031}', '\u{1031}', Gcb::SpacingMark),
+    ('\u{1032}', '\u{1037}', Gcb::Extend),
+    ('\u{1039}', '\u{103A}', Gcb::Extend),
+    ('\u{103B}', '\u{103C}', Gcb::SpacingMark),
+    ('\u{103D}', '\u{103E}', Gcb::Extend),
+    ('\u{1056}', '\u{1057}', Gcb::SpacingMark),
+    ('\u{1058}', '\u{1059}', Gcb::Extend),
+    ('\u{105E}', '\u{1060}', Gcb::Extend),
+    ('\u{1071}', '\u{1074}', Gcb::Extend),
+    ('\u{1082}', '\u{1082}', Gcb::Extend),
+    ('\u{1084}', '\u{1084}', Gcb::SpacingMark),
+    ('\u{1085}', '\u{1086}', Gcb::Extend),
+    ('\u{108D}', '\u{108D}', Gcb::Extend),
+    ('\u{109D}', '\u{109D}', Gcb::Extend),
+    ('\u{1100}', '\u{115F}', Gcb::L),
+    ('\u{1160}', '\u{11A7}', Gcb::V),
+    ('\u{11A8}', '\u{11FF}', Gcb::T),
+    ('\u{135D}', '\u{135F}', Gcb::Extend),
+    ('\u{1712}', '\u{1714}', Gcb::Extend),
+    ('\u{1715}', '\u{1715}', Gcb::SpacingMark),
+    ('\u{1732}', '\u{1733}', Gcb::Extend),
+    ('\u{1734}', '\u{1734}', Gcb::SpacingMark),
+    ('\u{1752}', '\u{1753}', Gcb::Extend),
+    ('\u{1772}', '\u{1773}', Gcb::Extend),
+    ('\u{17B4}', '\u{17B5}', Gcb::Extend),
+    ('\u{17B6}', '\u{17B6}', Gcb::SpacingMark),
+    ('\u{17B7}', '\u{17BD}', Gcb::Extend),
+    ('\u{17BE}', '\u{17C5}', Gcb::SpacingMark),
+    ('\u{17C6}', '\u{17C6}', Gcb::Extend),
+    ('\u{17C7}', '\u{17C8}', Gcb::SpacingMark),
+    ('\u{17C9}', '\u{17D3}', Gcb::Extend),
+    ('\u{17DD}', '\u{17DD}', Gcb::Extend),
+    ('\u{180B}', '\u{180D}', Gcb::Extend),
+    ('\u{180E}', '\u{180E}', Gcb::Control),
+    ('\u{180F}', '\u{180F}', Gcb::Extend),
+    ('\u{1885}', '\u{1886}', Gcb::Extend),
+    ('\u{18A9}', '\u{18A9}', Gcb::Extend),
+    ('\u{1920}', '\u{1922}', Gcb::Extend),
+    ('\u{1923}', '\u{1926}', Gcb::SpacingMark),
+    ('\u{1927}', '\u{1928}', Gcb::Extend),
+    ('\u{1929}', '\u{192B}', Gcb::SpacingMark),
+    ('\u{1930}', '\u{1931}', Gcb::SpacingMark),
+    ('\u{1932}', '\u{1932}', Gcb::Extend),
+    ('\u{1933}', '\u{1938}', Gcb::SpacingMark),
+    ('\u{1939}', '\u{193B}', Gcb::Extend),
+    ('\u{1A17}', '\u{1A18}', Gcb::Extend),
+    ('\u{1A19}', '\u{1A1A}', Gcb::SpacingMark),
+    ('\u{1A1B}', '\u{1A1B}', Gcb::Extend),
+    ('\u{1A55}', '\u{1A55}', Gcb::SpacingMark),
+    ('\u{1A56}', '\u{1A56}', Gcb::Extend),
+    ('\u{1A57}', '\u{1A57}', Gcb::SpacingMark),
+    ('\u{1A58}', '\u{1A5E}', Gcb::Extend),
+    ('\u{1A60}', '\u{1A60}', Gcb::Extend),
+    ('\u{1A62}', '\u{1A62}', Gcb::Extend),
+    ('\u{1A65}', '\u{1A6C}', Gcb::Extend),
+    ('\u{1A6D}', '\u{1A72}', Gcb::SpacingMark),
+    ('\u{1A73}', '\u{1A7C}', Gcb::Extend),
+    ('\u{1A7F}', '\u{1A7F}', Gcb::Extend),
+    ('\u{1AB0}', '\u{1ACE}', Gcb::Extend),
+    ('\u{1B00}', '\u{1B03}', Gcb::Extend),
+    ('\u{1B04}', '\u{1B04}', Gcb::SpacingMark),
+    ('\u{1B34}', '\u{1B34}', Gcb::Extend),
+    ('\u{1B35}', '\u{1B35}', Gcb::SpacingMark),
+    ('\u{1B36}', '\u{1B3A}', Gcb::Extend),
+    ('\u{1B3B}', '\u{1B3B}', Gcb::SpacingMark),
+    ('\u{1B3C}', '\u{1B3C}', Gcb::Extend),
+    ('\u{1B3D}', '\u{1B41}', Gcb::SpacingMark),
+    ('\u{1B42}', '\u{1B42}', Gcb::Extend),
+    ('\u{1B43}', '\u{1B44}', Gcb::SpacingMark),
+    ('\u{1B6B}', '\u{1B73}', Gcb::Extend),
+    ('\u{1B80}', '\u{1B81}', Gcb::Extend),
+    ('\u{1B82}', '\u{1B82}', Gcb::SpacingMark),
+    ('\u{1BA1}', '\u{1BA1}', Gcb::SpacingMark),
+    ('\u{1BA2}', '\u{1BA5}', Gcb::Extend),
+    ('\u{1BA6}', '\u{1BA7}', Gcb::SpacingMark),
+    ('\u{1BA8}', '\u{1BA9}', Gcb::Extend),
+    ('\u{1BAA}', '\u{1BAA}', Gcb::SpacingMark),
+    ('\u{1BAB}', '\u{1BAD}', Gcb::Extend),
+    ('\u{1BE6}', '\u{1BE6}', Gcb::Extend),
+    ('\u{1BE7}', '\u{1BE7}', Gcb::SpacingMark),
+    ('\u{1BE8}', '\u{1BE9}', Gcb::Extend),
+    ('\u{1BEA}', '\u{1BEC}', Gcb::SpacingMark),
+    ('\u{1BED}', '\u{1BED}', Gcb::Extend),
+    ('\u{1BEE}', '\u{1BEE}', Gcb::SpacingMark),
+    ('\u{1BEF}', '\u{1BF1}', Gcb::Extend),
+    ('\u{1BF2}', '\u{1BF3}', Gcb::SpacingMark),
+    ('\u{1C24}', '\u{1C2B}', Gcb::SpacingMark),
+    ('\u{1C2C}', '\u{1C33}', Gcb::Extend),
+    ('\u{1C34}', '\u{1C35}', Gcb::SpacingMark),
+    ('\u{1C36}', '\u{1C37}', Gcb::Extend),
+    ('\u{1CD0}', '\u{1CD2}', Gcb::Extend),
+    ('\u{1CD4}', '\u{1CE0}', Gcb::Extend),
+    ('\u{1CE1}', '\u{1CE1}', Gcb::SpacingMark),
+    ('\u{1CE2}', '\u{1CE8}', Gcb::Extend),
+    ('\u{1CED}', '\u{1CED}', Gcb::Extend),
+    ('\u{1CF4}', '\u{1CF4}', Gcb::Extend),
+    ('\u{1CF7}', '\u{1CF7}', Gcb::SpacingMark),
+    ('\u{1CF8}', '\u{1CF9}', Gcb::Extend),
+    ('\u{1DC0}', '\u{1DFF}', Gcb::Extend),
+    ('\u{200B}', '\u{200B}', Gcb::Control),
+    ('\u{200C}', '\u{200C}', Gcb::Extend),
+    ('\u{200D}', '\u{200D}', Gcb::Zwj),
+    ('\u{200E}', '\u{200F}', Gcb::Control),
+    ('\u{2028}', '\u{202E}', Gcb::Control),
+    ('\u{203C}', '\u{203C}', Gcb::ExtendedPictographic),
+    ('\u{2049}', '\u{2049}', Gcb::ExtendedPictographic),
+    ('\u{2060}', '\u{206F}', Gcb::Control),
+    ('\u{20D0}', '\u{20F0}', Gcb::Extend),
+    ('\u{2122}', '\u{2122}', Gcb::ExtendedPictographic),
+    ('\u{2139}', '\u{2139}', Gcb::ExtendedPictographic),
+    ('\u{2194}', '\u{2199}', Gcb::ExtendedPictographic),
+    ('\u{21A9}', '\u{21AA}', Gcb::ExtendedPictographic),
+    ('\u{231A}', '\u{231B}', Gcb::ExtendedPictographic),
+    ('\u{2328}', '\u{2328}', Gcb::ExtendedPictographic),
+    ('\u{2388}', '\u{2388}', Gcb::ExtendedPictographic),
+    ('\u{23CF}', '\u{23CF}', Gcb::ExtendedPictographic),
+    ('\u{23E9}', '\u{23F3}', Gcb::ExtendedPictographic),
+    ('\u{23F8}', '\u{23FA}', Gcb::ExtendedPictographic),
+    ('\u{24C2}', '\u{24C2}', Gcb::ExtendedPictographic),
+    ('\u{25AA}', '\u{25AB}', Gcb::ExtendedPictographic),
+    ('\u{25B6}', '\u{25B6}', Gcb::ExtendedPictographic),
+    ('\u{25C0}', '\u{25C0}', Gcb::ExtendedPictographic),
+    ('\u{25FB}', '\u{25FE}', Gcb::ExtendedPictographic),
+    ('\u{2600}', '\u{2605}', Gcb::ExtendedPictographic),
+    ('\u{2607}', '\u{2612}', Gcb::ExtendedPictographic),
+    ('\u{2614}', '\u{2685}', Gcb::ExtendedPictographic),
+    ('\u{2690}', '\u{2705}', Gcb::ExtendedPictographic),
+    ('\u{2708}', '\u{2712}', Gcb::ExtendedPictographic),
+    ('\u{2714}', '\u{2714}', Gcb::ExtendedPictographic),
+    ('\u{2716}', '\u{2716}', Gcb::ExtendedPictographic),
+    ('\u{271D}', '\u{271D}', Gcb::ExtendedPictographic),
+    ('\u{2721}', '\u{2721}', Gcb::ExtendedPictographic),
+    ('\u{2728}', '\u{2728}', Gcb::ExtendedPictographic),
+    ('\u{2733}', '\u{2734}', Gcb::ExtendedPictographic),
+    ('\u{2744}', '\u{2744}', Gcb::ExtendedPictographic),
+    ('\u{2747}', '\u{2747}', Gcb::ExtendedPictographic),
+    ('\u{274C}', '\u{274C}', Gcb::ExtendedPictographic),
+    ('\u{274E}', '\u{274E}', Gcb::ExtendedPictographic),
+    ('\u{2753}', '\u{2755}', Gcb::ExtendedPictographic),
+    ('\u{2757}', '\u{2757}', Gcb::ExtendedPictographic),
+    ('\u{2763}', '\u{2767}', Gcb::ExtendedPictographic),
+    ('\u{2795}', '\u{2797}', Gcb::ExtendedPictographic),
+    ('\u{27A1}', '\u{27A1}', Gcb::ExtendedPictographic),
+    ('\u{27B0}', '\u{27B0}', Gcb::ExtendedPictographic),
+    ('\u{27BF}', '\u{27BF}', Gcb::ExtendedPictographic),
+    ('\u{2934}', '\u{2935}', Gcb::ExtendedPictographic),
+    ('\u{2B05}', '\u{2B07}', Gcb::ExtendedPictographic),
+    ('\u{2B1B}', '\u{2B1C}', Gcb::ExtendedPictographic),
+    ('\u{2B50}', '\u{2B50}', Gcb::ExtendedPictographic),
+    ('\u{2B55}', '\u{2B55}', Gcb::ExtendedPictographic),
+    ('\u{2CEF}', '\u{2CF1}', Gcb::Extend),
+    ('\u{2D7F}', '\u{2D7F}', Gcb::Extend),
+    ('\u{2DE0}', '\u{2DFF}', Gcb::Extend),
+    ('\u{302A}', '\u{302F}', Gcb::Extend),
+    ('\u{3030}', '\u{3030}', Gcb::ExtendedPictographic),
+    ('\u{303D}', '\u{303D}', Gcb::ExtendedPictographic),
+    ('\u{3099}', '\u{309A}', Gcb::Extend),
+    ('\u{3297}', '\u{3297}', Gcb::ExtendedPictographic),
+    ('\u{3299}', '\u{3299}', Gcb::ExtendedPictographic),
+    ('\u{A66F}', '\u{A672}', Gcb::Extend),
+    ('\u{A674}', '\u{A67D}', Gcb::Extend),
+    ('\u{A69E}', '\u{A69F}', Gcb::Extend),
+    ('\u{A6F0}', '\u{A6F1}', Gcb::Extend),
+    ('\u{A802}', '\u{A802}', Gcb::Extend),
+    ('\u{A806}', '\u{A806}', Gcb::Extend),
+    ('\u{A80B}', '\u{A80B}', Gcb::Extend),
+    ('\u{A823}', '\u{A824}', Gcb::SpacingMark),
+    ('\u{A825}', '\u{A826}', Gcb::Extend),
+    ('\u{A827}', '\u{A827}', Gcb::SpacingMark),
+    ('\u{A82C}', '\u{A82C}', Gcb::Extend),
+    ('\u{A880}', '\u{A881}', Gcb::SpacingMark),
+    ('\u{A8B4}', '\u{A8C3}', Gcb::SpacingMark),
+    ('\u{A8C4}', '\u{A8C5}', Gcb::Extend),
+    ('\u{A8E0}', '\u{A8F1}', Gcb::Extend),
+    ('\u{A8FF}', '\u{A8FF}', Gcb::Extend),
+    ('\u{A926}', '\u{A92D}', Gcb::Extend),
+    ('\u{A947}', '\u{A951}', Gcb::Extend),
+    ('\u{A952}', '\u{A953}', Gcb::SpacingMark),
+    ('\u{A960}', '\u{A97C}', Gcb::L),
+    ('\u{A980}', '\u{A982}', Gcb::Extend),
+    ('\u{A983}', '\u{A983}', Gcb::SpacingMark),
+    ('\u{A9B3}', '\u{A9B3}', Gcb::Extend),
+    ('\u{A9B4}', '\u{A9B5}', Gcb::SpacingMark),
+    ('\u{A9B6}', '\u{A9B9}', Gcb::Extend),
+    ('\u{A9BA}', '\u{A9BB}', Gcb::SpacingMark),
+    ('\u{A9BC}', '\u{A9BD}', Gcb::Extend),
+    ('\u{A9BE}', '\u{A9C0}', Gcb::SpacingMark),
+    ('\u{A9E5}', '\u{A9E5}', Gcb::Extend),
+    ('\u{AA29}', '\u{AA2E}', Gcb::Extend),
+    ('\u{AA2F}', '\u{AA30}', Gcb::SpacingMark),
+    ('\u{AA31}', '\u{AA32}', Gcb::Extend),
+    ('\u{AA33}', '\u{AA34}', Gcb::SpacingMark),
+    ('\u{AA35}', '\u{AA36}', Gcb::Extend),
+    ('\u{AA43}', '\u{AA43}', Gcb::Extend),
+    ('\u{AA4C}', '\u{AA4C}', Gcb::Extend),
+    ('\u{AA4D}', '\u{AA4D}', Gcb::SpacingMark),
+    ('\u{AA7C}', '\u{AA7C}', Gcb::Extend),
+    ('\u{AAB0}', '\u{AAB0}', Gcb::Extend),
+    ('\u{AAB2}', '\u{AAB4}', Gcb::Extend),
+    ('\u{AAB7}', '\u{AAB8}', Gcb::Extend),
+    ('\u{AABE}', '\u{AABF}', Gcb::Extend),
+    ('\u{AAC1}', '\u{AAC1}', Gcb::Extend),
+    ('\u{AAEB}', '\u{AAEB}', Gcb::SpacingMark),
+    ('\u{AAEC}', '\u{AAED}', Gcb::Extend),
+    ('\u{AAEE}', '\u{AAEF}', Gcb::SpacingMark),
+    ('\u{AAF5}', '\u{AAF5}', Gcb::SpacingMark),
+    ('\u{AAF6}', '\u{AAF6}', Gcb::Extend),
+    ('\u{ABE3}', '\u{ABE4}', Gcb::SpacingMark),
+    ('\u{ABE5}', '\u{ABE5}', Gcb::Extend),
+    ('\u{ABE6}', '\u{ABE7}', Gcb::SpacingMark),
+    ('\u{ABE8}', '\u{ABE8}', Gcb::Extend),
+    ('\u{ABE9}', '\u{ABEA}', Gcb::SpacingMark),
+    ('\u{ABEC}', '\u{ABEC}', Gcb::SpacingMark),
+    ('\u{ABED}', '\u{ABED}', Gcb::Extend),
+    ('\u{D7B0}', '\u{D7C6}', Gcb::V),
+    ('\u{D7CB}', '\u{D7FB}', Gcb::T),
+    ('\u{FB1E}', '\u{FB1E}', Gcb::Extend),
+    ('\u{FE00}', '\u{FE0F}', Gcb::Extend),
+    ('\u{FE20}', '\u{FE2F}', Gcb::Extend),
+    ('\u{FEFF}', '\u{FEFF}', Gcb::Control),
+    ('\u{FF9E}', '\u{FF9F}', Gcb::Extend),
+    ('\u{FFF0}', '\u{FFFB}', Gcb::Control),
+    ('\u{101FD}', '\u{101FD}', Gcb::Extend),
+    ('\u{102E0}', '\u{102E0}', Gcb::Extend),
+    ('\u{10376}', '\u{1037A}', Gcb::Extend),
+    ('\u{10A01}', '\u{10A03}', Gcb::Extend),
+    ('\u{10A05}', '\u{10A06}', Gcb::Extend),
+    ('\u{10A0C}', '\u{10A0F}', Gcb::Extend),
+    ('\u{10A38}', '\u{10A3A}', Gcb::Extend),
+    ('\u{10A3F}', '\u{10A3F}', Gcb::Extend),
+    ('\u{10AE5}', '\u{10AE6}', Gcb::Extend),
+    ('\u{10D24}', '\u{10D27}', Gcb::Extend),
+    ('\u{10EAB}', '\u{10EAC}', Gcb::Extend),
+    ('\u{10F46}', '\u{10F50}', Gcb::Extend),
+    ('\u{10F82}', '\u{10F85}', Gcb::Extend),
+    ('\u{11000}', '\u{11000}', Gcb::SpacingMark),
+    ('\u{11001}', '\u{11001}', Gcb::Extend),
+    ('\u{11002}', '\u{11002}', Gcb::SpacingMark),
+    ('\u{11038}', '\u{11046}', Gcb::Extend),
+    ('\u{11070}', '\u{11070}', Gcb::Extend),
+    ('\u{11073}', '\u{11074}', Gcb::Extend),
+    ('\u{1107F}', '\u{11081}', Gcb::Extend),
+    ('\u{11082}', '\u{11082}', Gcb::SpacingMark),
+    ('\u{110B0}', '\u{110B2}', Gcb::SpacingMark),
+    ('\u{110B3}', '\u{110B6}', Gcb::Extend),
+    ('\u{110B7}', '\u{110B8}', Gcb::SpacingMark),
+    ('\u{110B9}', '\u{110BA}', Gcb::Extend),
+    ('\u{110BD}', '\u{110BD}', Gcb::Prepend),
+    ('\u{110C2}', '\u{110C2}', Gcb::Extend),
+    ('\u{110CD}', '\u{110CD}', Gcb::Prepend),
+    ('\u{11100}', '\u{11102}', Gcb::Extend),
+    ('\u{11127}', '\u{1112B}', Gcb::Extend),
+    ('\u{1112C}', '\u{1112C}', Gcb::SpacingMark),
+    ('\u{1112D}', '\u{11134}', Gcb::Extend),
+    ('\u{11145}', '\u{11146}', Gcb::SpacingMark),
+    ('\u{11173}', '\u{11173}', Gcb::Extend),
+    ('\u{11180}', '\u{11181}', Gcb::Extend),
+    ('\u{11182}', '\u{11182}', Gcb::SpacingMark),
+    ('\u{111B3}', '\u{111B5}', Gcb::SpacingMark),
+    ('\u{111B6}', '\u{111BE}', Gcb::Extend),
+    ('\u{111BF}', '\u{111C0}', Gcb::SpacingMark),
+    ('\u{111C2}', '\u{111C3}', Gcb::Prepend),
+    ('\u{111C9}', '\u{111CC}', Gcb::Extend),
+    ('\u{111CE}', '\u{111CE}', Gcb::SpacingMark),
+    ('\u{111CF}', '\u{111CF}', Gcb::Extend),
+    ('\u{1122C}', '\u{1122E}', Gcb::SpacingMark),
+    ('\u{1122F}', '\u{11231}', Gcb::Extend),
+    ('\u{11232}', '\u{11233}', Gcb::SpacingMark),
+    ('\u{11234}', '\u{11234}', Gcb::Extend),
+    ('\u{11235}', '\u{11235}', Gcb::SpacingMark),
+    ('\u{11236}', '\u{11237}', Gcb::Extend),
+    ('\u{1123E}', '\u{1123E}', Gcb::Extend),
+    ('\u{112DF}', '\u{112DF}', Gcb::Extend),
+    ('\u{112E0}', '\u{112E2}', Gcb::SpacingMark),
+    ('\u{112E3}', '\u{112EA}', Gcb::Extend),
+    ('\u{11300}', '\u{11301}', Gcb::Extend),
+    ('\u{11302}', '\u{11303}', Gcb::SpacingMark),
+    ('\u{1133B}', '\u{1133C}', Gcb::Extend),
+    ('\u{1133E}', '\u{1133E}', Gcb::Extend),
+    ('\u{1133F}', '\u{1133F}', Gcb::SpacingMark),
+    ('\u{11340}', '\u{11340}', Gcb::Extend),
+    ('\u{11341}', '\u{11344}', Gcb::SpacingMark),
+    ('\u{11347}', '\u{11348}', Gcb::SpacingMark),
+    ('\u{1134B}', '\u{1134D}', Gcb::SpacingMark),
+    ('\u{11357}', '\u{11357}', Gcb::Extend),
+    ('\u{11362}', '\u{11363}', Gcb::SpacingMark),
+    ('\u{11366}', '\u{1136C}', Gcb::Extend),
+    ('\u{11370}', '\u{11374}', Gcb::Extend),
+    ('\u{11435}', '\u{11437}', Gcb::SpacingMark),
+    ('\u{11438}', '\u{1143F}', Gcb::Extend),
+    ('\u{11440}', '\u{11441}', Gcb::SpacingMark),
+    ('\u{11442}', '\u{11444}', Gcb::Extend),
+    ('\u{11445}', '\u{11445}', Gcb::SpacingMark),
+    ('\u{11446}', '\u{11446}', Gcb::Extend),
+    ('\u{1145E}', '\u{1145E}', Gcb::Extend),
+    ('\u{114B0}', '\u{114B0}', Gcb::Extend),
+    ('\u{114B1}', '\u{114B2}', Gcb::SpacingMark),
+    ('\u{114B3}', '\u{114B8}', Gcb::Extend),
+    ('\u{114B9}', '\u{114B9}', Gcb::SpacingMark),
+    ('\u{114BA}', '\u{114BA}', Gcb::Extend),
+    ('\u{114BB}', '\u{114BC}', Gcb::SpacingMark),
+    ('\u{114BD}', '\u{114BD}', Gcb::Extend),
+    ('\u{114BE}', '\u{114BE}', Gcb::SpacingMark),
+    ('\u{114BF}', '\u{114C0}', Gcb::Extend),
+    ('\u{114C1}', '\u{114C1}', Gcb::SpacingMark),
+    ('\u{114C2}', '\u{114C3}', Gcb::Extend),
+    ('\u{115AF}', '\u{115AF}', Gcb::Extend),
+    ('\u{115B0}', '\u{115B1}', Gcb::SpacingMark),
+    ('\u{115B2}', '\u{115B5}', Gcb::Extend),
+    ('\u{115B8}', '\u{115BB}', Gcb::SpacingMark),
+    ('\u{115BC}', '\u{115BD}', Gcb::Extend),
+    ('\u{115BE}', '\u{115BE}', Gcb::SpacingMark),
+    ('\u{115BF}', '\u{115C0}', Gcb::Extend),
+    ('\u{115DC}', '\u{115DD}', Gcb::Extend),
+    ('\u{11630}', '\u{11632}', Gcb::SpacingMark),
+    ('\u{11633}', '\u{1163A}', Gcb::Extend),
+    ('\u{1163B}', '\u{1163C}', Gcb::SpacingMark),
+    ('\u{1163D}', '\u{1163D}', Gcb::Extend),
+    ('\u{1163E}', '\u{1163E}', Gcb::SpacingMark),
+    ('\u{1163F}', '\u{11640}', Gcb::Extend),
+    ('\u{116AB}', '\u{116AB}', Gcb::Extend),
+    ('\u{116AC}', '\u{116AC}', Gcb::SpacingMark),
+    ('\u{116AD}', '\u{116AD}', Gcb::Extend),
+    ('\u{116AE}', '\u{116AF}', Gcb::SpacingMark),
+    ('\u{116B0}', '\u{116B5}', Gcb::Extend),
+    ('\u{116B6}', '\u{116B6}', Gcb::SpacingMark),
+    ('\u{116B7}', '\u{116B7}', Gcb::Extend),
+    ('\u{1171D}', '\u{1171F}', Gcb::Extend),
+    ('\u{11722}', '\u{11725}', Gcb::Extend),
+    ('\u{11726}', '\u{11726}', Gcb::SpacingMark),
+    ('\u{11727}', '\u{1172B}', Gcb::Extend),
+    ('\u{1182C}', '\u{1182E}', Gcb::SpacingMark),
+    ('\u{1182F}', '\u{11837}', Gcb::Extend),
+    ('\u{11838}', '\u{11838}', Gcb::SpacingMark),
+    ('\u{11839}', '\u{1183A}', Gcb::Extend),
+    ('\u{11930}', '\u{11930}', Gcb::Extend),
+    ('\u{11931}', '\u{11935}', Gcb::SpacingMark),
+    ('\u{11937}', '\u{11938}', Gcb::SpacingMark),
+    ('\u{1193B}', '\u{1193C}', Gcb::Extend),
+    ('\u{1193D}', '\u{1193D}', Gcb::SpacingMark),
+    ('\u{1193E}', '\u{1193E}', Gcb::Extend),
+    ('\u{1193F}', '\u{1193F}', Gcb::Prepend),
+    ('\u{11940}', '\u{11940}', Gcb::SpacingMark),
+    ('\u{11941}', '\u{11941}', Gcb::Prepend),
+    ('\u{11942}', '\u{11942}', Gcb::SpacingMark),
+    ('\u{11943}', '\u{11943}', Gcb::Extend),
+    ('\u{119D1}', '\u{119D3}', Gcb::SpacingMark),
+    ('\u{119D4}', '\u{119D7}', Gcb::Extend),
+    ('\u{119DA}', '\u{119DB}', Gcb::Extend),
+    ('\u{119DC}', '\u{119DF}', Gcb::SpacingMark),
+    ('\u{119E0}', '\u{119E0}', Gcb::Extend),
+    ('\u{119E4}', '\u{119E4}', Gcb::SpacingMark),
+    ('\u{11A01}', '\u{11A0A}', Gcb::Extend),
+    ('\u{11A33}', '\u{11A38}', Gcb::Extend),
+    ('\u{11A39}', '\u{11A39}', Gcb::SpacingMark),
+    ('\u{11A3A}', '\u{11A3A}', Gcb::Prepend),
+    ('\u{11A3B}', '\u{11A3E}', Gcb::Extend),
+    ('\u{11A47}', '\u{11A47}', Gcb::Extend),
+    ('\u{11A51}', '\u{11A56}', Gcb::Extend),
+    ('\u{11A57}', '\u{11A58}', Gcb::SpacingMark),
+    ('\u{11A59}', '\u{11A5B}', Gcb::Extend),
+    ('\u{11A84}', '\u{11A89}', Gcb::Prepend),
+    ('\u{11A8A}', '\u{11A96}', Gcb::Extend),
+    ('\u{11A97}', '\u{11A97}', Gcb::SpacingMark),
+    ('\u{11A98}', '\u{11A99}', Gcb::Extend),
+    ('\u{11C2F}', '\u{11C2F}', Gcb::SpacingMark),
+    ('\u{11C30}', '\u{11C36}', Gcb::Extend),
+    ('\u{11C38}', '\u{11C3D}', Gcb::Extend),
+    ('\u{11C3E}', '\u{11C3E}', Gcb::SpacingMark),
+    ('\u{11C3F}', '\u{11C3F}', Gcb::Extend),
+    ('\u{11C92}', '\u{11CA7}', Gcb::Extend),
+    ('\u{11CA9}', '\u{11CA9}', Gcb::SpacingMark),
+    ('\u{11CAA}', '\u{11CB0}', Gcb::Extend),
+    ('\u{11CB1}', '\u{11CB1}', Gcb::SpacingMark),
+    ('\u{11CB2}', '\u{11CB3}', Gcb::Extend),
+    ('\u{11CB4}', '\u{11CB4}', Gcb::SpacingMark),
+    ('\u{11CB5}', '\u{11CB6}', Gcb::Extend),
+    ('\u{11D31}', '\u{11D36}', Gcb::Extend),
+    ('\u{11D3A}', '\u{11D3A}', Gcb::Extend),
+    ('\u{11D3C}', '\u{11D3D}', Gcb::Extend),
+    ('\u{11D3F}', '\u{11D45}', Gcb::Extend),
+    ('\u{11D46}', '\u{11D46}', Gcb::Prepend),
+    ('\u{11D47}', '\u{11D47}', Gcb::Extend),
+    ('\u{11D8A}', '\u{11D8E}', Gcb::SpacingMark),
+    ('\u{11D90}', '\u{11D91}', Gcb::Extend),
+    ('\u{11D93}', '\u{11D94}', Gcb::SpacingMark),
+    ('\u{11D95}', '\u{11D95}', Gcb::Extend),
+    ('\u{11D96}', '\u{11D96}', Gcb::SpacingMark),
+    ('\u{11D97}', '\u{11D97}', Gcb::Extend),
+    ('\u{11EF3}', '\u{11EF4}', Gcb::Extend),
+    ('\u{11EF5}', '\u{11EF6}', Gcb::SpacingMark),
+    ('\u{13430}', '\u{13438}', Gcb::Control),
+    ('\u{16AF0}', '\u{16AF4}', Gcb::Extend),
+    ('\u{16B30}', '\u{16B36}', Gcb::Extend),
+    ('\u{16F4F}', '\u{16F4F}', Gcb::Extend),
+    ('\u{16F51}', '\u{16F87}', Gcb::SpacingMark),
+    ('\u{16F8F}', '\u{16F92}', Gcb::Extend),
+    ('\u{16FE4}', '\u{16FE4}', Gcb::Extend),
+    ('\u{16FF0}', '\u{16FF1}', Gcb::SpacingMark),
+    ('\u{1BC9D}', '\u{1BC9E}', Gcb::Extend),
+    ('\u{1BCA0}', '\u{1BCA3}', Gcb::Control),
+    ('\u{1CF00}', '\u{1CF2D}', Gcb::Extend),
+    ('\u{1CF30}', '\u{1CF46}', Gcb::Extend),
+    ('\u{1D165}', '\u{1D165}', Gcb::Extend),
+    ('\u{1D166}', '\u{1D166}', Gcb::SpacingMark),
+    ('\u{1D167}', '\u{1D169}', Gcb::Extend),
+    ('\u{1D16D}', '\u{1D16D}', Gcb::SpacingMark),
+    ('\u{1D16E}', '\u{1D172}', Gcb::Extend),
+    ('\u{1D173}', '\u{1D17A}', Gcb::Control),
+    ('\u{1D17B}', '\u{1D182}', Gcb::Extend),
+    ('\u{1D185}', '\u{1D18B}', Gcb::Extend),
+    ('\u{1D1AA}', '\u{1D1AD}', Gcb::Extend),
+    ('\u{1D242}', '\u{1D244}', Gcb::Extend),
+    ('\u{1DA00}', '\u{1DA36}', Gcb::Extend),
+    ('\u{1DA3B}', '\u{1DA6C}', Gcb::Extend),
+    ('\u{1DA75}', '\u{1DA75}', Gcb::Extend),
+    ('\u{1DA84}', '\u{1DA84}', Gcb::Extend),
+    ('\u{1DA9B}', '\u{1DA9F}', Gcb::Extend),
+    ('\u{1DAA1}', '\u{1DAAF}', Gcb::Extend),
+    ('\u{1E000}', '\u{1E006}', Gcb::Extend),
+    ('\u{1E008}', '\u{1E018}', Gcb::Extend),
+    ('\u{1E01B}', '\u{1E021}', Gcb::Extend),
+    ('\u{1E023}', '\u{1E024}', Gcb::Extend),
+    ('\u{1E026}', '\u{1E02A}', Gcb::Extend),
+    ('\u{1E130}', '\u{1E136}', Gcb::Extend),
+    ('\u{1E2AE}', '\u{1E2AE}', Gcb::Extend),
+    ('\u{1E2EC}', '\u{1E2EF}', Gcb::Extend),
+    ('\u{1E8D0}', '\u{1E8D6}', Gcb::Extend),
+    ('\u{1E944}', '\u{1E94A}', Gcb::Extend),
+    ('\u{1F000}', '\u{1F0FF}', Gcb::ExtendedPictographic),
+    ('\u{1F10D}', '\u{1F10F}', Gcb::ExtendedPictographic),
+    ('\u{1F12F}', '\u{1F12F}', Gcb::ExtendedPictographic),
+    ('\u{1F16C}', '\u{1F171}', Gcb::ExtendedPictographic),
+    ('\u{1F17E}', '\u{1F17F}', Gcb::ExtendedPictographic),
+    ('\u{1F18E}', '\u{1F18E}', Gcb::ExtendedPictographic),
+    ('\u{1F191}', '\u{1F19A}', Gcb::ExtendedPictographic),
+    ('\u{1F1AD}', '\u{1F1E5}', Gcb::ExtendedPictographic),
+    ('\u{1F1E6}', '\u{1F1FF}', Gcb::RegionalIndicator),
+    ('\u{1F201}', '\u{1F20F}', Gcb::ExtendedPictographic),
+    ('\u{1F21A}', '\u{1F21A}', Gcb::ExtendedPictographic),
+    ('\u{1F22F}', '\u{1F22F}', Gcb::ExtendedPictographic),
+    ('\u{1F232}', '\u{1F23A}', Gcb::ExtendedPictographic),
+    ('\u{1F23C}', '\u{1F23F}', Gcb::ExtendedPictographic),
+    ('\u{1F249}', '\u{1F3FA}', Gcb::ExtendedPictographic),
+    ('\u{1F3FB}', '\u{1F3FF}', Gcb::Extend),
+    ('\u{1F400}', '\u{1F53D}', Gcb::ExtendedPictographic),
+    ('\u{1F546}', '\u{1F64F}', Gcb::ExtendedPictographic),
+    ('\u{1F680}', '\u{1F6FF}', Gcb::ExtendedPictographic),
+    ('\u{1F774}', '\u{1F77F}', Gcb::ExtendedPictographic),
+    ('\u{1F7D5}', '\u{1F7FF}', Gcb::ExtendedPictographic),
+    ('\u{1F80C}', '\u{1F80F}', Gcb::ExtendedPictographic),
+    ('\u{1F848}', '\u{1F84F}', Gcb::ExtendedPictographic),
+    ('\u{1F85A}', '\u{1F85F}', Gcb::ExtendedPictographic),
+    ('\u{1F888}', '\u{1F88F}', Gcb::ExtendedPictographic),
+    ('\u{1F8AE}', '\u{1F8FF}', Gcb::ExtendedPictographic),
+    ('\u{1F90C}', '\u{1F93A}', Gcb::ExtendedPictographic),
+    ('\u{1F93C}', '\u{1F945}', Gcb::ExtendedPictographic),
+    ('\u{1F947}', '\u{1FAFF}', Gcb::ExtendedPictographic),
+    ('\u{1FC00}', '\u{1FFFD}', Gcb::ExtendedPictographic),
+    ('\u{E0000}', '\u{E001F}', Gcb::Control),
+    ('\u{E0020}', '\u{E007F}', Gcb::Extend),
+    ('\u{E0080}', '\u{E00FF}', Gcb::Control),
+    ('\u{E0100}', '\u{E01EF}', Gcb::Extend),
+    ('\u{E01F0}', '\u{E0FFF}', Gcb::Control),
+];
@@ -0,0 +1,6 @@
+// Unicode data tables. Generated from the Unicode character database so the
+// library doesn't need any dependencies.
+
+pub(crate) mod folding;
+pub(crate) mod grapheme;
+pub(crate) mod width;
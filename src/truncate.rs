@@ -0,0 +1,151 @@
+/// Truncate a string to a number of characters with an ellipsis.
+///
+/// The ellipsis counts toward the maximum: the result never has more than
+/// 'max' characters. The cut is on a character, word or grapheme boundary
+/// (TruncateBoundary) at the end, the start or in the middle of the string
+/// (TruncatePosition).
+/// Examples (ellipsis "…"):
+///   Char, End     : truncate_chars("Café au lait with milk", 16, options) => "Café au lait wi…"
+///   Word, End     : truncate_chars("Café au lait with milk", 16, options) => "Café au lait…"
+///   Word, Start   : truncate_chars("Café au lait with milk", 16, options) => "…lait with milk"
+///   Char, Middle  : truncate_chars("Café au lait with milk", 16, options) => "Café au …th milk"
+use crate::byte_index;
+use crate::grapheme::boundaries;
+
+/// Where truncate_chars can cut the string.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum TruncateBoundary {
+    /// Any character.
+    #[default]
+    Char,
+    /// Before or after whitespace, the whitespace at the cut is removed. A word
+    /// that is too long is cut at a character boundary.
+    Word,
+    /// Between grapheme clusters: "e" and a combining accent stay together.
+    Grapheme,
+}
+
+/// Which part of the string truncate_chars removes.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum TruncatePosition {
+    /// Keep the start: "Café au…"
+    #[default]
+    End,
+    /// Keep the start and the end: "Café…milk"
+    Middle,
+    /// Keep the end: "…with milk"
+    Start,
+}
+
+/// Options for truncate_chars.
+/// Default: ellipsis "…", cut at any character, at the end.
+/// Example:
+///   TruncateOptions { ellipsis: "...", boundary: TruncateBoundary::Word, ..Default::default() }
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct TruncateOptions<'a> {
+    pub ellipsis: &'a str,
+    pub boundary: TruncateBoundary,
+    pub position: TruncatePosition,
+}
+
+impl Default for TruncateOptions<'_> {
+    fn default() -> Self {
+        TruncateOptions {
+            ellipsis: "…",
+            boundary: TruncateBoundary::Char,
+            position: TruncatePosition::End,
+        }
+    }
+}
+
+// -------------------------------------------------------------------------
+
+/// Truncate a string to at most 'max' characters, including the ellipsis.
+/// Returns the string unchanged if it has 'max' characters or less.
+/// If the ellipsis itself has more than 'max' characters, the result is the
+/// first 'max' characters of the ellipsis.
+/// With TruncatePosition::Middle, the start gets the extra character if the
+/// number of kept characters is odd.
+/// Examples:
+///   let options = TruncateOptions { boundary: TruncateBoundary::Word, ..Default::default() };
+///   truncate_chars("Café au lait with milk", 16, options) => "Café au lait…"
+///   truncate_chars("Café", 10, options)                   => "Café"
+pub fn truncate_chars(s: &str, max: usize, options: TruncateOptions) -> String {
+    let len: usize = s.chars().count();
+    if len <= max {
+        return s.to_owned();
+    }
+
+    let ellipsis: &str = options.ellipsis;
+    let Some(keep) = max.checked_sub(ellipsis.chars().count()) else {
+        return ellipsis[..byte_index(ellipsis, max)].to_owned();
+    };
+
+    let (mut head, mut tail) = match options.position {
+        TruncatePosition::End => (head(s, keep, options.boundary), ""),
+        TruncatePosition::Start => ("", tail(s, len - keep, options.boundary)),
+        TruncatePosition::Middle => {
+            let tail_keep: usize = keep / 2;
+            (head(s, keep - tail_keep, options.boundary), tail(s, len - tail_keep, options.boundary))
+        }
+    };
+    if options.boundary == TruncateBoundary::Word {
+        // No whitespace next to the ellipsis, also after a cut at a character
+        head = head.trim_end();
+        tail = tail.trim_start();
+    }
+
+    let mut result: String = String::with_capacity(head.len() + ellipsis.len() + tail.len());
+    result.push_str(head);
+    result.push_str(ellipsis);
+    result.push_str(tail);
+    result
+}
+
+/// The start of 's' with at most 'count' characters, cut on 'boundary'.
+fn head(s: &str, count: usize, boundary: TruncateBoundary) -> &str {
+    let pos: usize = byte_index(s, count);
+
+    match boundary {
+        TruncateBoundary::Char => &s[..pos],
+        TruncateBoundary::Grapheme => {
+            let bounds: Vec<usize> = boundaries(s);
+            let cut: usize = bounds[bounds.partition_point(|&b| b <= pos) - 1];
+            &s[..cut]
+        }
+        TruncateBoundary::Word => {
+            // Cut before whitespace, the character at 'pos' can be the whitespace
+            let limit: usize = pos + s[pos..].chars().next().map_or(0, char::len_utf8);
+            match s[..limit].rfind(char::is_whitespace).map(|cut| s[..cut].trim_end()) {
+                Some(word_head) if !word_head.is_empty() => word_head,
+                _ => head(s, count, TruncateBoundary::Char),
+            }
+        }
+    }
+}
+
+/// The end of 's' from character index 'start', cut on 'boundary' (at 'start'
+/// or after).
+fn tail(s: &str, start: usize, boundary: TruncateBoundary) -> &str {
+    let pos: usize = byte_index(s, start);
+
+    match boundary {
+        TruncateBoundary::Char => &s[pos..],
+        TruncateBoundary::Grapheme => {
+            let bounds: Vec<usize> = boundaries(s);
+            let cut: usize = bounds[bounds.partition_point(|&b| b < pos)];
+            &s[cut..]
+        }
+        TruncateBoundary::Word => {
+            // Cut after whitespace, the character before 'pos' can be the whitespace
+            let from: usize = s[..pos].char_indices().next_back().map_or(0, |(i, _)| i);
+            let word_tail: Option<&str> = s[from..]
+                .find(char::is_whitespace)
+                .map(|i| s[from + i..].trim_start());
+            match word_tail {
+                Some(word_tail) if !word_tail.is_empty() => word_tail,
+                _ => tail(s, start, TruncateBoundary::Char),
+            }
+        }
+    }
+}
@@ -0,0 +1,206 @@
+/// UTF-16 code unit indexing.
+///
+/// Same functions as the character indexed functions of the library, but
+/// indexed by UTF-16 code units, for offsets from JavaScript, Java or C# (.NET).
+/// Characters outside the Basic Multilingual Plane ("😀", U+1F600) are a
+/// surrogate pair: 1 character, 2 UTF-16 code units.
+///   "a😀b".substr(1, 1)         => "😀" (characters)
+///   utf16::substr("a😀b", 1, 2) => "😀" (UTF-16 code units)
+///   utf16::substr("a😀b", 1, 1) => "\u{FFFD}" (half of the surrogate pair)
+/// A substring that splits a surrogate pair can't be stored in a Rust String:
+/// the lone surrogate is replaced by U+FFFD (REPLACEMENT CHARACTER).
+/// The conversion functions report an index that splits a surrogate pair with
+/// an error (Utf16IndexError).
+use crate::{calc_substring, resolve_char_range, Utf16IndexError};
+
+pub trait Utf16Index {
+    fn utf16_len(&self) -> usize;
+    fn utf16_indexof(&self, searchstring: &str, start_index: usize) -> Option<usize>;
+    fn utf16_substr(&self, start_index: isize, length: isize) -> String;
+    fn utf16_substring(&self, start_index: isize, end_index: isize) -> String;
+    fn utf16_str_remove(&self, start_index: isize, length: isize) -> String;
+}
+
+impl Utf16Index for str {
+    fn utf16_len(&self) -> usize {
+        len(self)
+    }
+
+    fn utf16_indexof(&self, searchstring: &str, start_index: usize) -> Option<usize> {
+        indexof(self, searchstring, start_index)
+    }
+
+    fn utf16_substr(&self, start_index: isize, length: isize) -> String {
+        substr(self, start_index, length)
+    }
+
+    fn utf16_substring(&self, start_index: isize, end_index: isize) -> String {
+        substring(self, start_index, end_index)
+    }
+
+    fn utf16_str_remove(&self, start_index: isize, length: isize) -> String {
+        str_remove(self, start_index, length)
+    }
+}
+
+impl Utf16Index for String {
+    fn utf16_len(&self) -> usize {
+        len(self)
+    }
+
+    fn utf16_indexof(&self, searchstring: &str, start_index: usize) -> Option<usize> {
+        indexof(self, searchstring, start_index)
+    }
+
+    fn utf16_substr(&self, start_index: isize, length: isize) -> String {
+        substr(self, start_index, length)
+    }
+
+    fn utf16_substring(&self, start_index: isize, end_index: isize) -> String {
+        substring(self, start_index, end_index)
+    }
+
+    fn utf16_str_remove(&self, start_index: isize, length: isize) -> String {
+        str_remove(self, start_index, length)
+    }
+}
+
+// -------------------------------------------------------------------------
+
+/// Length of a string in UTF-16 code units.
+pub fn len(s: &str) -> usize {
+    s.chars().map(char::len_utf16).sum()
+}
+
+/// UTF-16 code unit index of the character with index 'char_index'.
+/// 'char_index' can be equal to the number of characters (end of the string).
+/// Example:
+///   char_index_to_utf16("a😀b", 2) => Ok(3)
+pub fn char_index_to_utf16(s: &str, char_index: usize) -> Result<usize, Utf16IndexError> {
+    let mut utf16_index: usize = 0;
+    let mut chars = s.chars();
+
+    for _ in 0..char_index {
+        match chars.next() {
+            Some(c) => utf16_index += c.len_utf16(),
+            None => {
+                return Err(Utf16IndexError::OutOfRange {
+                    index: char_index,
+                    len: s.chars().count(),
+                })
+            }
+        }
+    }
+    Ok(utf16_index)
+}
+
+/// Character index of the character at UTF-16 code unit index 'utf16_index'.
+/// 'utf16_index' can be equal to the UTF-16 length (end of the string).
+/// Example:
+///   utf16_to_char_index("a😀b", 3) => Ok(2)
+///   utf16_to_char_index("a😀b", 2) => Err(Utf16IndexError::SplitSurrogatePair { index: 2 })
+pub fn utf16_to_char_index(s: &str, utf16_index: usize) -> Result<usize, Utf16IndexError> {
+    utf16_position(s, utf16_index).map(|(char_index, _)| char_index)
+}
+
+/// Byte position of the character at UTF-16 code unit index 'utf16_index'.
+/// 'utf16_index' can be equal to the UTF-16 length (end of the string).
+/// Example:
+///   utf16_to_byte("é😀b", 3) => Ok(6)
+pub fn utf16_to_byte(s: &str, utf16_index: usize) -> Result<usize, Utf16IndexError> {
+    utf16_position(s, utf16_index).map(|(_, byte_index)| byte_index)
+}
+
+/// (character index, byte position) of a UTF-16 code unit index.
+fn utf16_position(s: &str, utf16_index: usize) -> Result<(usize, usize), Utf16IndexError> {
+    let mut pos: usize = 0; // UTF-16 position of the character
+
+    for (char_index, (byte_index, c)) in s.char_indices().enumerate() {
+        if pos == utf16_index {
+            return Ok((char_index, byte_index));
+        }
+        pos += c.len_utf16();
+        if pos > utf16_index {
+            return Err(Utf16IndexError::SplitSurrogatePair { index: utf16_index });
+        }
+    }
+
+    if pos == utf16_index {
+        Ok((s.chars().count(), s.len()))
+    } else {
+        Err(Utf16IndexError::OutOfRange { index: utf16_index, len: pos })
+    }
+}
+
+// -------------------------------------------------------------------------
+
+/// Get the UTF-16 code unit position from one string into another. Start
+/// searching from UTF-16 index 'start_index'. Returns None if not found.
+/// If 'start_index' is inside a surrogate pair, the search starts at the next
+/// character.
+/// Example:
+///   utf16::indexof("😀a😀a", "a", 3) => Some(5)
+pub fn indexof(s: &str, searchstring: &str, start_index: usize) -> Option<usize> {
+    if searchstring.is_empty() {
+        return None;
+    }
+
+    // Byte position and UTF-16 position of the first character at start_index or after
+    let mut pos: usize = 0;
+    let mut from: Option<usize> = None;
+    for (byte_index, c) in s.char_indices() {
+        if pos >= start_index {
+            from = Some(byte_index);
+            break;
+        }
+        pos += c.len_utf16();
+    }
+    let from: usize = from?;
+
+    let found: usize = from + s[from..].find(searchstring)?;
+    Some(pos + len(&s[from..found]))
+}
+
+// -------------------------------------------------------------------------
+
+/// Remove a substring from a string. Beginning at UTF-16 index 'start_index'
+/// and take 'length' UTF-16 code units. Same rules as str_remove with
+/// characters (negative numbers count backwards).
+pub fn str_remove(s: &str, start_index: isize, length: isize) -> String {
+    let units: Vec<u16> = s.encode_utf16().collect();
+    let (start, end) = resolve_char_range(units.len(), start_index, length);
+    if start == end {
+        return s.to_owned();
+    }
+
+    let mut result: Vec<u16> = Vec::with_capacity(units.len() - (end - start));
+    result.extend_from_slice(&units[..start]);
+    result.extend_from_slice(&units[end..]);
+    String::from_utf16_lossy(&result)
+}
+
+// -------------------------------------------------------------------------
+
+/// Get a substring of a string, beginning at UTF-16 index 'start_index' and
+/// take 'length' UTF-16 code units. Same rules as substr with characters
+/// (negative numbers count backwards).
+/// Example:
+///   utf16::substr("a😀b", -3, 2) => "😀"
+pub fn substr(s: &str, start_index: isize, length: isize) -> String {
+    let units: Vec<u16> = s.encode_utf16().collect();
+    let (start, end) = resolve_char_range(units.len(), start_index, length);
+
+    String::from_utf16_lossy(&units[start..end])
+}
+
+// -------------------------------------------------------------------------
+
+/// Get a substring of a string beginning at UTF-16 index start_index up to and
+/// *excluding* the UTF-16 index end_index. Same rules as substring with
+/// characters (JavaScript substring).
+pub fn substring(s: &str, start_index: isize, end_index: isize) -> String {
+    let units: Vec<u16> = s.encode_utf16().collect();
+    let (start, end) = calc_substring(start_index, end_index);
+
+    String::from_utf16_lossy(&units[start.min(units.len())..end.min(units.len())])
+}
@@ -0,0 +1,83 @@
+/// Display width: substr and truncation measured in terminal columns.
+///
+/// Most characters take 1 column. East Asian wide and fullwidth characters
+/// (CJK, most emoji) take 2 columns. Combining marks, format characters (zero
+/// width joiner, variation selectors) and control characters take 0 columns.
+/// The width tables are part of the library (Unicode 14.0.0).
+/// A zero-width character stays with the character before it: substr_width
+/// and truncate_width never separate "e" and a combining accent.
+/// Examples:
+///   display_width("abc")    => 3
+///   display_width("日本")   => 4
+///   display_width("e\u{301}") => 1
+use crate::tables::width;
+
+/// Display width of a character in terminal columns: 0, 1 or 2.
+/// Examples:
+///   char_width('a')      => 1
+///   char_width('日')     => 2
+///   char_width('\u{301}') => 0
+pub fn char_width(c: char) -> usize {
+    width::char_width(c)
+}
+
+/// Display width of a string in terminal columns.
+pub fn display_width(s: &str) -> usize {
+    s.chars().map(width::char_width).sum()
+}
+
+/// Get the part of a string from column 'start_col' with a display width of at
+/// most 'width' columns. Same as substru, measured in columns instead of characters.
+/// A wide character that is only partly inside the columns is not included,
+/// the result can be narrower than 'width'. Zero-width characters are included
+/// with the character before them.
+/// Examples:
+///   substr_width("日本語", 2, 4) => "本語"
+///   substr_width("日本語", 1, 4) => "本" (half of "日" and "語" not included)
+///   substr_width("ae\u{301}b", 1, 1) => "e\u{301}"
+pub fn substr_width(s: &str, start_col: usize, width: usize) -> String {
+    let end_col: usize = start_col.saturating_add(width);
+    let mut result: String = String::new();
+    let mut col: usize = 0;
+    let mut base_included: Option<bool> = None; // The last character with a width
+
+    for c in s.chars() {
+        let w: usize = width::char_width(c);
+        let included: bool = if w == 0 {
+            // Zero-width characters at the start of the string are at column 0
+            base_included.unwrap_or(start_col == 0 && end_col > 0)
+        } else {
+            let included: bool = col >= start_col && col + w <= end_col;
+            base_included = Some(included);
+            col += w;
+            included
+        };
+
+        if included {
+            result.push(c);
+        } else if col > end_col {
+            break;
+        }
+    }
+    result
+}
+
+/// Truncate a string to a display width of at most 'max_cols' columns. If the
+/// string is wider, it is cut and 'ellipsis' is added; the width of the
+/// ellipsis is part of 'max_cols'. If the ellipsis itself is wider than
+/// 'max_cols', the result is the part of the ellipsis that fits.
+/// Examples:
+///   truncate_width("日本語テキスト", 8, "…") => "日本語…"
+///   truncate_width("abc", 3, "…")            => "abc"
+///   truncate_width("abcdef", 4, "...")       => "a..."
+pub fn truncate_width(s: &str, max_cols: usize, ellipsis: &str) -> String {
+    if display_width(s) <= max_cols {
+        return s.to_owned();
+    }
+
+    let ellipsis_width: usize = display_width(ellipsis);
+    match max_cols.checked_sub(ellipsis_width) {
+        Some(cols) => substr_width(s, 0, cols) + ellipsis,
+        None => substr_width(ellipsis, 0, max_cols),
+    }
+}
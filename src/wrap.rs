@@ -0,0 +1,191 @@
+/// Word wrapping to a number of characters or terminal columns.
+///
+/// The words are separated by whitespace and added to a line as long as they
+/// fit (greedy), the whitespace between the words of a line is one space.
+/// A word is never split inside a character or a grapheme cluster: "e" and a
+/// combining accent stay together.
+/// Examples:
+///   wrap("The quick brown fox", 10) => ["The quick", "brown fox"]
+///   let options = WrapOptions { initial_indent: "- ", subsequent_indent: "  ", ..Default::default() };
+///   wrap_with("The quick brown fox", 10, options) => ["- The", "  quick", "  brown", "  fox"]
+use crate::grapheme::boundaries;
+use crate::pad::{measure, WidthMode};
+use crate::width::substr_width;
+
+/// Options for wrap_with.
+/// Default: break long words, keep the line endings of the text, no indent,
+/// width in characters.
+/// Example:
+///   WrapOptions { mode: WidthMode::Columns, ..Default::default() }
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct WrapOptions<'a> {
+    /// Split a word that is longer than the line. If false, the word is on a
+    /// line of its own that is longer than the width.
+    pub break_words: bool,
+    /// Keep the line endings of the text: every line of the text is wrapped
+    /// separately, an empty line stays an empty line. If false, line endings
+    /// are whitespace between words.
+    pub preserve_newlines: bool,
+    /// Prefix of the first line (of every line of the text if preserve_newlines).
+    pub initial_indent: &'a str,
+    /// Prefix of the other lines, a hanging indent or a quote prefix ("> ").
+    pub subsequent_indent: &'a str,
+    /// Unit of the width: characters or terminal columns.
+    pub mode: WidthMode,
+}
+
+impl Default for WrapOptions<'_> {
+    fn default() -> Self {
+        WrapOptions {
+            break_words: true,
+            preserve_newlines: true,
+            initial_indent: "",
+            subsequent_indent: "",
+            mode: WidthMode::Chars,
+        }
+    }
+}
+
+// -------------------------------------------------------------------------
+
+/// Wrap a text to lines of at most 'width' characters.
+/// Same as wrap_with with the default WrapOptions.
+/// Example:
+///   wrap("Café au lait\nwith milk", 8) => ["Café au", "lait", "with", "milk"]
+pub fn wrap(s: &str, width: usize) -> Vec<String> {
+    wrap_with(s, width, WrapOptions::default())
+}
+
+/// Wrap a text to lines of at most 'width' characters or terminal columns
+/// (options.mode). The width includes the indent. An indent that is not
+/// narrower than 'width' is cut to 'width' - 1, so at least one column is
+/// left for the words.
+/// Returns the lines without line endings, an empty text has no lines.
+/// A grapheme cluster that is wider than the line is on a line of its own.
+/// Examples:
+///   let options = WrapOptions { break_words: false, ..Default::default() };
+///   wrap_with("a verylongword", 5, options) => ["a", "verylongword"]
+///   wrap_with("a verylongword", 5, WrapOptions::default()) => ["a", "veryl", "ongwo", "rd"]
+///   let options = WrapOptions { initial_indent: ">>>>", ..Default::default() };
+///   wrap_with("ab", 3, options) => [">>a", "b"]
+pub fn wrap_with(s: &str, width: usize, options: WrapOptions) -> Vec<String> {
+    let initial_indent: String = clamp_indent(options.initial_indent, width, options.mode);
+    let subsequent_indent: String = clamp_indent(options.subsequent_indent, width, options.mode);
+    let options = WrapOptions {
+        initial_indent: &initial_indent,
+        subsequent_indent: &subsequent_indent,
+        ..options
+    };
+    let mut lines: Vec<String> = Vec::new();
+
+    if options.preserve_newlines {
+        for paragraph in s.lines() {
+            wrap_paragraph(paragraph, width, &options, &mut lines);
+        }
+    } else if !s.is_empty() {
+        wrap_paragraph(s, width, &options, &mut lines);
+    }
+    lines
+}
+
+/// Cut an indent to less than 'width' characters or columns.
+fn clamp_indent(indent: &str, width: usize, mode: WidthMode) -> String {
+    let max: usize = width.saturating_sub(1);
+    if measure(indent, mode) <= max {
+        return indent.to_owned();
+    }
+    match mode {
+        WidthMode::Chars => indent.chars().take(max).collect(),
+        WidthMode::Columns => substr_width(indent, 0, max),
+    }
+}
+
+/// Lines of a paragraph that is built line by line.
+struct LineBuilder<'a, 'o> {
+    lines: &'a mut Vec<String>,
+    options: &'o WrapOptions<'o>,
+    width: usize,
+    line: String, // Current line without the indent
+    line_width: usize,
+    first: bool, // 'line' is the first line of the paragraph
+}
+
+impl LineBuilder<'_, '_> {
+    fn indent(&self) -> &str {
+        if self.first {
+            self.options.initial_indent
+        } else {
+            self.options.subsequent_indent
+        }
+    }
+
+    /// Width available for words on the current line.
+    fn available(&self) -> usize {
+        self.width.saturating_sub(measure(self.indent(), self.options.mode))
+    }
+
+    fn push_word(&mut self, word: &str) {
+        let word_width: usize = measure(word, self.options.mode);
+        if !self.line.is_empty() && self.line_width + 1 + word_width > self.available() {
+            self.end_line();
+        }
+
+        if !self.line.is_empty() {
+            self.line.push(' ');
+            self.line_width += 1;
+        } else if word_width > self.available() && self.options.break_words {
+            self.push_broken_word(word);
+            return;
+        }
+        self.line.push_str(word);
+        self.line_width += word_width;
+    }
+
+    /// Add a word that is longer than the line, split at grapheme boundaries.
+    /// The last part stays on the current line, so the next word can follow it.
+    fn push_broken_word(&mut self, word: &str) {
+        let bounds: Vec<usize> = boundaries(word);
+        let mut start: usize = 0;
+
+        for pair in bounds.windows(2) {
+            let grapheme: &str = &word[pair[0]..pair[1]];
+            let grapheme_width: usize = measure(grapheme, self.options.mode);
+            if pair[0] > start && self.line_width + grapheme_width > self.available() {
+                self.line.push_str(&word[start..pair[0]]);
+                self.end_line();
+                start = pair[0];
+            }
+            self.line_width += grapheme_width;
+        }
+        self.line.push_str(&word[start..]);
+    }
+
+    fn end_line(&mut self) {
+        let line: String = std::mem::take(&mut self.line);
+        self.lines.push(format!("{}{}", self.indent(), line));
+        self.line_width = 0;
+        self.first = false;
+    }
+}
+
+/// Wrap one paragraph (a line of the text if preserve_newlines) and add the lines.
+fn wrap_paragraph(paragraph: &str, width: usize, options: &WrapOptions, lines: &mut Vec<String>) {
+    let mut builder = LineBuilder {
+        lines,
+        options,
+        width,
+        line: String::new(),
+        line_width: 0,
+        first: true,
+    };
+
+    let mut words = paragraph.split_whitespace().peekable();
+    if words.peek().is_none() {
+        builder.lines.push(String::new()); // Empty line
+        return;
+    }
+    for word in words {
+        builder.push_word(word);
+    }
+    builder.end_line();
+}
@@ -1,5 +1,28 @@
-use string_manipulation_utf8::CharString;
-use string_manipulation_utf8::{indexof, str_remove, substr, substr_end, substring, substru, str_concat};
+use string_manipulation_utf8::{CharPattern, CharString};
+use string_manipulation_utf8::{indexof, lastindexof, str_remove, str_removeu, substr, substr_end, substring, substru, str_concat};
+use string_manipulation_utf8::{grapheme, GraphemeString};
+use string_manipulation_utf8::js;
+use string_manipulation_utf8::{utf16, Utf16Index, Utf16IndexError};
+use string_manipulation_utf8::positions::{self, Snap};
+use string_manipulation_utf8::{str_remove_cow, substr_end_slice, substr_slice, substring_slice, substru_slice};
+use string_manipulation_utf8::CharIndex;
+use string_manipulation_utf8::{LineIndex, Position, PositionEncoding};
+use string_manipulation_utf8::CharStringMut;
+use string_manipulation_utf8::{char_width, display_width, substr_width, truncate_width};
+use string_manipulation_utf8::{pad_center, pad_left, pad_right, pad_with, CenterBias, PadSide, WidthMode};
+use string_manipulation_utf8::{truncate_chars, TruncateBoundary, TruncateOptions, TruncatePosition};
+use string_manipulation_utf8::{wrap, wrap_with, WrapOptions};
+use string_manipulation_utf8::{align_columns, justify, justify_line, justify_with, Alignment};
+use string_manipulation_utf8::{indexof_all, match_indices_chars};
+use string_manipulation_utf8::{indexof_with, SearchOptions};
+use string_manipulation_utf8::str_remove_matches;
+use string_manipulation_utf8::{str_insert, str_splice};
+use string_manipulation_utf8::{char_slice, char_slice_signed};
+use string_manipulation_utf8::{py_slice, pyslice};
+use string_manipulation_utf8::{MultiIndexOf, MultiMatch};
+use string_manipulation_utf8::{try_str_remove, try_substr, try_substr_end, try_substring, try_substru, StrIndexError};
+use string_manipulation_utf8::resolve_char_range;
+use std::borrow::Cow;
 
 #[test]
 fn test_substr() {
@@ -135,6 +158,49 @@ fn test_substr() {
 
 // -----------------------------------------------------------------------------
 
+#[test]
+fn test_resolve_char_range() {
+    assert_eq!(resolve_char_range(10, 2, 3), (2, 5));
+    assert_eq!(resolve_char_range(10, -3, 2), (7, 9));
+    assert_eq!(resolve_char_range(10, 5, -2), (4, 6));
+    assert_eq!(resolve_char_range(10, 2, isize::MAX), (2, 10));
+    assert_eq!(resolve_char_range(10, 2, isize::MIN), (0, 3));
+    assert_eq!(resolve_char_range(10, isize::MIN, isize::MIN), (0, 0));
+    assert_eq!(resolve_char_range(10, isize::MAX, isize::MAX), (0, 0));
+    assert_eq!(resolve_char_range(10, -10, 1), (0, 1));
+    assert_eq!(resolve_char_range(10, -11, 1), (0, 0));
+    assert_eq!(resolve_char_range(10, 10, -1), (0, 0));
+    assert_eq!(resolve_char_range(0, 0, 1), (0, 0));
+
+    // Huge strings, lengths that don't fit in isize
+    assert_eq!(resolve_char_range(usize::MAX, isize::MAX, isize::MAX), (isize::MAX as usize, usize::MAX - 1));
+    assert_eq!(resolve_char_range(usize::MAX, -1, 2), (usize::MAX - 1, usize::MAX));
+    assert_eq!(resolve_char_range(usize::MAX, isize::MIN, -1), (usize::MAX - isize::MIN.unsigned_abs(), usize::MAX - isize::MIN.unsigned_abs() + 1));
+    assert_eq!(resolve_char_range(usize::MAX, 0, isize::MIN), (0, 1));
+
+    // Reference calculation without overflow (i128)
+    let numbers: [isize; 11] = [isize::MIN, isize::MIN + 1, -12, -11, -10, -1, 0, 1, 9, 10, isize::MAX];
+    for len in [0usize, 1, 10, 11] {
+        for start_index in numbers {
+            for length in numbers {
+                let n: i128 = len as i128;
+                let start: i128 = if start_index < 0 { n + start_index as i128 } else { start_index as i128 };
+                let length_: i128 = length as i128;
+                let expected: (usize, usize) = if length == 0 || start < 0 || start >= n {
+                    (0, 0)
+                } else if length > 0 {
+                    (start as usize, (start + length_).min(n) as usize)
+                } else {
+                    ((start + length_ + 1).max(0) as usize, (start + 1) as usize)
+                };
+                assert_eq!(resolve_char_range(len, start_index, length), expected);
+            }
+        }
+    }
+}
+
+// -----------------------------------------------------------------------------
+
 #[test]
 fn test_substru() {
     let s1: &str = "0123456789";
@@ -285,6 +351,334 @@ fn test_indexof() {
     assert_eq!(s2.indexof("home", 100), None); // Out of bounds
     assert_eq!(s2.indexof("", 0), None); // Search nothing
     assert_eq!(s2.indexof("not found", 0), None); // Not found
+
+    // Multi-byte characters near the end of the string
+    assert_eq!(indexof("é", "éa", 0), None);
+    assert_eq!(indexof("aé", "éé", 0), None);
+    assert_eq!(indexof("éèçà", "çà", 0), Some(2));
+    assert_eq!(indexof("éèçà", "à", 3), Some(3));
+    assert_eq!(indexof("éèçà", "à", 4), None);
+    assert_eq!(indexof("😀a😀b", "😀b", 1), Some(2));
+    assert_eq!(indexof(s1, "e", usize::MAX), None);
+
+    // Same results as a search character by character, for every start index
+    let chars: Vec<char> = s1.chars().collect();
+    for searchstring in ["test", "123", "éèçà", "à 1", "e", "home", "x"] {
+        let search: Vec<char> = searchstring.chars().collect();
+        for start_index in 0..=chars.len() + 1 {
+            let expected: Option<usize> = (start_index..chars.len())
+                .find(|&i| chars[i..].starts_with(&search));
+            assert_eq!(indexof(s1, searchstring, start_index), expected);
+        }
+    }
+}
+
+// -----------------------------------------------------------------------------
+
+#[test]
+fn test_indexof_with() {
+    let s1: &str = "Bonjour Émile, ÉMILE et emile. Die Straße. ΣΊΣΥΦΟΣ";
+    let s2: String = s1.to_owned();
+    let exact: SearchOptions = SearchOptions::default();
+    let case: SearchOptions = SearchOptions { ignore_case: true, ignore_accents: false };
+    let accents: SearchOptions = SearchOptions { ignore_case: false, ignore_accents: true };
+    let both: SearchOptions = SearchOptions { ignore_case: true, ignore_accents: true };
+
+    assert_eq!(indexof_with(s1, "emile", 0, exact), Some(24));
+    assert_eq!(indexof_with(s1, "emile", 0, case), Some(24));
+    assert_eq!(indexof_with(s1, "émile", 0, case), Some(8));
+    assert_eq!(indexof_with(s1, "émile", 9, case), Some(15));
+    assert_eq!(indexof_with(s1, "Emile", 0, accents), Some(8));
+    assert_eq!(indexof_with(s1, "EMILE", 0, accents), Some(15));
+    assert_eq!(indexof_with(s1, "emile", 0, both), Some(8));
+    assert_eq!(indexof_with(s1, "emile", 9, both), Some(15));
+    assert_eq!(indexof_with(s1, "emile", 16, both), Some(24));
+    assert_eq!(indexof_with(s1, "emile", 25, both), None);
+
+    // Folding changes the length, the position is in the original string
+    assert_eq!(indexof_with(s1, "STRASSE", 0, case), Some(35));
+    assert_eq!(indexof_with(s1, "strasse.", 0, case), Some(35));
+    assert_eq!(indexof_with(s1, ". ", 35, case), Some(41));
+    assert_eq!(indexof_with("ßa", "A", 0, case), Some(1));
+    assert_eq!(indexof_with("ß", "s", 0, case), None); // Part of a character
+    assert_eq!(indexof_with("Straße", "STRAẞE", 0, case), Some(0)); // Capital sharp s
+    assert_eq!(indexof_with(s1, "σίσυφος", 0, case), Some(43)); // Final sigma
+    assert_eq!(indexof_with(s1, "σισυφος", 0, both), Some(43));
+    assert_eq!(indexof_with("ﬀ", "FF", 0, case), Some(0)); // Ligature
+
+    // Decomposed characters (e + combining acute)
+    assert_eq!(indexof_with("Cafe\u{301} noir", "Café", 0, accents), Some(0));
+    assert_eq!(indexof_with("Café noir", "cafe\u{301}", 0, both), Some(0));
+    assert_eq!(indexof_with("Cafe\u{301} noir", "noir", 0, accents), Some(6));
+
+    assert_eq!(indexof_with(s1, "", 0, both), None); // Search nothing
+    assert_eq!(indexof_with(s1, "\u{301}", 0, accents), None); // Search nothing after folding
+    assert_eq!(indexof_with(s1, "not found", 0, both), None);
+    assert_eq!(indexof_with(s1, "emile", 100, both), None); // Out of bounds
+    assert_eq!(indexof_with("", "a", 0, both), None);
+
+    // str
+    assert_eq!(s1.indexof_with("emile", 0, both), Some(8));
+    // String
+    assert_eq!(s2.indexof_with("emile", 0, both), Some(8));
+    // CharIndex
+    assert_eq!(CharIndex::new(s1).indexof_with("emile", 0, both), Some(8));
+}
+
+// -----------------------------------------------------------------------------
+
+#[test]
+fn test_multi_indexof() {
+    let s1: &str = "Test 123 éèçà 123 test home";
+    let needles: MultiIndexOf = MultiIndexOf::new(["test", "123", "çà 1", "23 t", "é", "", "home"]);
+    let m = |needle: usize, start: usize, length: usize| MultiMatch { needle, start, length };
+
+    assert_eq!(needles.needle_count(), 7);
+    assert_eq!(needles.needle(2), Some("çà 1"));
+    assert_eq!(needles.needle(7), None);
+
+    assert_eq!(
+        needles.find_all(s1),
+        [m(1, 5, 3), m(4, 9, 1), m(2, 11, 4), m(1, 14, 3), m(3, 15, 4), m(0, 18, 4), m(6, 23, 4)]
+    );
+    // Ordered by end position
+    assert_eq!(
+        needles.find_iter(s1).collect::<Vec<MultiMatch>>(),
+        [m(1, 5, 3), m(4, 9, 1), m(2, 11, 4), m(1, 14, 3), m(3, 15, 4), m(0, 18, 4), m(6, 23, 4)]
+    );
+
+    // Same positions as indexof_all for each needle
+    for id in 0..needles.needle_count() {
+        let expected: Vec<usize> = indexof_all(s1, needles.needle(id).unwrap(), true).collect();
+        let found: Vec<usize> = needles.find_all(s1).iter().filter(|m| m.needle == id).map(|m| m.start).collect();
+        assert_eq!(found, expected);
+    }
+
+    // Overlapping needles and suffixes
+    let overlap: MultiIndexOf = MultiIndexOf::new(["he", "she", "his", "hers", "ééé", "éé"]);
+    assert_eq!(
+        overlap.find_all("ushers"),
+        [m(1, 1, 3), m(0, 2, 2), m(3, 2, 4)]
+    );
+    assert_eq!(overlap.find_all("éééé"), [m(4, 0, 3), m(5, 0, 2), m(4, 1, 3), m(5, 1, 2), m(5, 2, 2)]);
+
+    // indexof: first match from start_index, the longest at the same position
+    assert_eq!(needles.indexof(s1, 0), Some(m(1, 5, 3)));
+    assert_eq!(needles.indexof(s1, 6), Some(m(4, 9, 1)));
+    assert_eq!(needles.indexof(s1, 10), Some(m(2, 11, 4)));
+    assert_eq!(needles.indexof(s1, 19), Some(m(6, 23, 4)));
+    assert_eq!(needles.indexof(s1, 24), None);
+    assert_eq!(needles.indexof(s1, 100), None); // Out of bounds
+    assert_eq!(overlap.indexof("ushers", 0), Some(m(1, 1, 3)));
+    assert_eq!(overlap.indexof("éééé", 0), Some(m(4, 0, 3)));
+
+    // No needles, empty string
+    let none: MultiIndexOf = MultiIndexOf::new(Vec::<String>::new());
+    assert_eq!(none.find_all(s1), []);
+    assert_eq!(none.indexof(s1, 0), None);
+    assert_eq!(needles.find_all(""), []);
+}
+
+// -----------------------------------------------------------------------------
+
+#[test]
+fn test_lastindexof() {
+    let s1: &str = "Test 123 éèçà 123 test home";
+    let s2: String = s1.to_owned();
+
+    assert_eq!(lastindexof(s1, "123", isize::MAX), Some(14));
+    assert_eq!(lastindexof(s1, "123", -1), Some(14));
+    assert_eq!(lastindexof(s1, "123", 14), Some(14));
+    assert_eq!(lastindexof(s1, "123", 13), Some(5));
+    assert_eq!(lastindexof(s1, "123", -14), Some(5));
+    assert_eq!(lastindexof(s1, "123", 5), Some(5));
+    assert_eq!(lastindexof(s1, "123", 4), None);
+    assert_eq!(lastindexof(s1, "éèçà", -1), Some(9));
+    assert_eq!(lastindexof(s1, "à", 12), Some(12));
+    assert_eq!(lastindexof(s1, "à", 11), None);
+    assert_eq!(lastindexof(s1, "e", -1), Some(26));
+    assert_eq!(lastindexof(s1, "T", 0), Some(0));
+    assert_eq!(lastindexof(s1, "T", -27), Some(0));
+    assert_eq!(lastindexof(s1, "T", -28), None); // Out of bounds
+    assert_eq!(lastindexof(s1, "T", isize::MIN), None); // Out of bounds
+    assert_eq!(lastindexof(s1, "", -1), None); // Search nothing
+    assert_eq!(lastindexof(s1, "not found", -1), None); // Not found
+    assert_eq!(lastindexof("", "a", 0), None);
+    assert_eq!(lastindexof("😀a😀b", "😀", -1), Some(2));
+    assert_eq!(lastindexof("😀a😀b", "😀", 1), Some(0));
+    assert_eq!(lastindexof("éé", "éé", 1), Some(0));
+
+    // Same results as a search character by character, for every from_index
+    let chars: Vec<char> = s1.chars().collect();
+    let total: isize = chars.len() as isize;
+    for searchstring in ["test", "123", "éèçà", "à 1", "e", "home", "x"] {
+        let search: Vec<char> = searchstring.chars().collect();
+        for from_index in -total - 1..=total + 1 {
+            let from: isize = if from_index < 0 { total + from_index } else { from_index.min(total - 1) };
+            let expected: Option<usize> = (0..=from)
+                .rev()
+                .find(|&i| chars[i as usize..].starts_with(&search))
+                .map(|i| i as usize);
+            assert_eq!(lastindexof(s1, searchstring, from_index), expected);
+        }
+    }
+
+    // str
+    assert_eq!(s1.lastindexof("123", -1), Some(14));
+    assert_eq!(s1.lastindexof("123", 13), Some(5));
+
+    // String
+    assert_eq!(s2.lastindexof("123", -1), Some(14));
+    assert_eq!(s2.lastindexof("123", 13), Some(5));
+
+    // CharIndex
+    let long: String = s1.repeat(5);
+    let index: CharIndex = CharIndex::new(&long);
+    for from_index in [isize::MIN, -136, -135, -100, -1, 0, 5, 40, 100, 134, 135, isize::MAX] {
+        assert_eq!(index.lastindexof("123", from_index), long.lastindexof("123", from_index));
+        assert_eq!(index.lastindexof("éèçà", from_index), long.lastindexof("éèçà", from_index));
+    }
+}
+
+// -----------------------------------------------------------------------------
+
+#[test]
+fn test_char_pattern() {
+    let s1: &str = "Test 123 éèçà 123, test; home";
+    let s2: String = s1.to_owned();
+    let separators: [char; 2] = [',', ';'];
+    let searchstring: String = "test".to_owned();
+
+    // indexof
+    assert_eq!(indexof(s1, |c: char| c.is_ascii_digit(), 0), Some(5));
+    assert_eq!(indexof(s1, |c: char| c.is_ascii_digit(), 8), Some(14));
+    assert_eq!(indexof(s1, char::is_whitespace, 5), Some(8));
+    assert_eq!(indexof(s1, 'ç', 0), Some(11));
+    assert_eq!(indexof(s1, separators, 0), Some(17));
+    assert_eq!(indexof(s1, &separators, 18), Some(23));
+    assert_eq!(indexof(s1, &separators[..], 24), None);
+    assert_eq!(indexof(s1, &['à', 'é'][..], 0), Some(9));
+    assert_eq!(indexof(s1, &searchstring, 0), Some(19));
+    assert_eq!(indexof(s1, 'x', 0), None);
+    assert_eq!(indexof(s1, [], 0), None); // Empty set of characters
+
+    // String references that deref to &str
+    let needles: Vec<&str> = vec!["123", "home"];
+    let found: Vec<Option<usize>> = needles.iter().map(|n| s1.indexof(n, 0)).collect();
+    assert_eq!(found, [Some(5), Some(25)]);
+    let cow: Cow<str> = Cow::Borrowed("éèçà");
+    assert_eq!(indexof(s1, &cow, 0), Some(9));
+    let boxed: Box<str> = "test".into();
+    assert_eq!(lastindexof(s1, &boxed, -1), Some(19));
+
+    // lastindexof
+    assert_eq!(lastindexof(s1, |c: char| c.is_ascii_digit(), -1), Some(16));
+    assert_eq!(lastindexof(s1, |c: char| c.is_ascii_digit(), 13), Some(7));
+    assert_eq!(lastindexof(s1, 'é', -1), Some(9));
+    assert_eq!(lastindexof(s1, 'é', 9), Some(9));
+    assert_eq!(lastindexof(s1, 'é', 8), None);
+    assert_eq!(lastindexof(s1, separators, -1), Some(23));
+    assert_eq!(lastindexof(s1, separators, 22), Some(17));
+
+    // indexof_all, match_indices_chars
+    assert_eq!(indexof_all(s1, char::is_numeric, false).collect::<Vec<usize>>(), [5, 6, 7, 14, 15, 16]);
+    assert_eq!(indexof_all(s1, separators, true).collect::<Vec<usize>>(), [17, 23]);
+    assert_eq!(
+        match_indices_chars(s1, ['é', 'à'], false).collect::<Vec<(usize, usize)>>(),
+        [(9, 9), (12, 15)]
+    );
+
+    // A custom pattern with empty matches doesn't loop forever
+    struct Empty;
+    impl CharPattern for Empty {
+        fn find_in(&mut self, _haystack: &str) -> Option<(usize, usize)> {
+            Some((0, 0))
+        }
+        fn rfind_in(&mut self, _haystack: &str, start_max: usize) -> Option<(usize, usize)> {
+            Some((start_max, start_max))
+        }
+    }
+    assert_eq!(indexof_all("aé", Empty, false).collect::<Vec<usize>>(), [0, 1, 2]);
+    assert_eq!(str_remove_matches("aé", Empty), "aé");
+
+    // str
+    assert_eq!(s1.indexof(char::is_numeric, 0), Some(5));
+    assert_eq!(s1.lastindexof(separators, -1), Some(23));
+    assert_eq!(s1.indexof_all('3', false).collect::<Vec<usize>>(), [7, 16]);
+
+    // String
+    assert_eq!(s2.indexof(char::is_numeric, 0), Some(5));
+    assert_eq!(s2.lastindexof(separators, -1), Some(23));
+    assert_eq!(s2.indexof_all('3', false).collect::<Vec<usize>>(), [7, 16]);
+
+    // CharIndex
+    let index: CharIndex = CharIndex::new(s1);
+    assert_eq!(index.indexof(char::is_numeric, 8), Some(14));
+    assert_eq!(index.lastindexof(separators, 22), Some(17));
+    assert_eq!(index.indexof_all('3', false).collect::<Vec<usize>>(), [7, 16]);
+}
+
+// -----------------------------------------------------------------------------
+
+#[test]
+fn test_indexof_all() {
+    let s1: &str = "Test 123 éèçà 123 test home";
+    let s2: String = s1.to_owned();
+
+    assert_eq!(indexof_all(s1, "123", false).collect::<Vec<usize>>(), [5, 14]);
+    assert_eq!(indexof_all(s1, "e", false).collect::<Vec<usize>>(), [1, 19, 26]);
+    assert_eq!(indexof_all(s1, "à", false).collect::<Vec<usize>>(), [12]);
+    assert_eq!(indexof_all(s1, "not found", false).count(), 0);
+    assert_eq!(indexof_all(s1, "", false).count(), 0); // Search nothing
+    assert_eq!(indexof_all("", "a", false).count(), 0);
+
+    // Overlapping
+    assert_eq!(indexof_all("aaaa", "aa", false).collect::<Vec<usize>>(), [0, 2]);
+    assert_eq!(indexof_all("aaaa", "aa", true).collect::<Vec<usize>>(), [0, 1, 2]);
+    assert_eq!(indexof_all("ééé", "éé", false).collect::<Vec<usize>>(), [0]);
+    assert_eq!(indexof_all("ééé", "éé", true).collect::<Vec<usize>>(), [0, 1]);
+    assert_eq!(indexof_all("😀😀😀", "😀😀", true).collect::<Vec<usize>>(), [0, 1]);
+
+    // Same results as calling indexof with start_index = pos + 1
+    let mut expected: Vec<usize> = Vec::new();
+    let mut pos: usize = 0;
+    while let Some(found) = indexof(s1, "e", pos) {
+        expected.push(found);
+        pos = found + 1;
+    }
+    assert_eq!(indexof_all(s1, "e", true).collect::<Vec<usize>>(), expected);
+
+    // Character and byte positions
+    assert_eq!(
+        match_indices_chars(s1, "123", false).collect::<Vec<(usize, usize)>>(),
+        [(5, 5), (14, 18)]
+    );
+    assert_eq!(
+        match_indices_chars("é-é", "é", false).collect::<Vec<(usize, usize)>>(),
+        [(0, 0), (2, 3)]
+    );
+    for (index, index_byte) in match_indices_chars(s1, "é", true) {
+        assert_eq!(s1.substr(index as isize, 1), &s1[index_byte..index_byte + 2]);
+    }
+
+    // Iterator is fused
+    let mut iter = indexof_all("ab", "b", false);
+    assert_eq!(iter.next(), Some(1));
+    assert_eq!(iter.next(), None);
+    assert_eq!(iter.next(), None);
+
+    // str
+    assert_eq!(s1.indexof_all("123", false).collect::<Vec<usize>>(), [5, 14]);
+    assert_eq!(s1.match_indices_chars("123", false).collect::<Vec<(usize, usize)>>(), [(5, 5), (14, 18)]);
+
+    // String
+    assert_eq!(s2.indexof_all("123", false).collect::<Vec<usize>>(), [5, 14]);
+    assert_eq!(s2.match_indices_chars("123", false).collect::<Vec<(usize, usize)>>(), [(5, 5), (14, 18)]);
+
+    // CharIndex
+    let index: CharIndex = CharIndex::new(s1);
+    assert_eq!(index.indexof_all("123", false).collect::<Vec<usize>>(), [5, 14]);
+    assert_eq!(index.match_indices_chars("123", false).collect::<Vec<(usize, usize)>>(), [(5, 5), (14, 18)]);
 }
 
 // -----------------------------------------------------------------------------
@@ -311,6 +705,498 @@ fn test_str_remove() {
     assert_eq!(s2.str_remove(3, 100), "Tes"); // Remove all characters from position 3. 100 > number of characters.
     assert_eq!(s2.str_remove(0, 0), s2); // Remove nothing.
     assert_eq!(s2.str_remove(500, 2), s2); // Remove characters that don't exist. Out of bounds.
+
+    // Negative numbers, same characters as substr
+    assert_eq!(str_remove("0123456789", -3, 2), "01234569");
+    assert_eq!(str_remove("0123456789", 5, -2), "01236789");
+    assert_eq!(str_remove("0123456789", -1, isize::MIN), "");
+    assert_eq!(str_remove("", 0, 1), ""); // Panicked before
+    assert_eq!(str_remove("", -1, -1), "");
+
+    // Unsigned
+    assert_eq!(str_removeu(s1, 14, 4), "Test 123 éèçà test home");
+    assert_eq!(str_removeu(s1, 3, usize::MAX), "Tes");
+    assert_eq!(str_removeu(s1, usize::MAX, usize::MAX), s1);
+    assert_eq!(str_removeu("", 0, 1), "");
+    assert_eq!(s1.str_removeu(9, 5), "Test 123 123 test home");
+    assert_eq!(s2.str_removeu(9, 5), "Test 123 123 test home");
+
+    // Every start and length on empty, ASCII, 2-byte, 3-byte and 4-byte characters
+    let inputs: [&str; 6] = ["", "a", "abcdef", "éèçà", "a€b€c", "\u{1F600}x\u{10348}\u{1F44D}y"];
+    let numbers: [isize; 13] = [isize::MIN, isize::MIN + 1, -7, -6, -5, -2, -1, 0, 1, 2, 5, 6, isize::MAX];
+    let unsigned: [usize; 8] = [0, 1, 2, 5, 6, 7, usize::MAX - 1, usize::MAX];
+
+    for s in inputs {
+        let chars: Vec<char> = s.chars().collect();
+
+        for start_index in numbers {
+            for length in numbers {
+                // The removed characters are the substring returned by substr
+                let removed: String = str_remove(s, start_index, length);
+                let sub: String = substr(s, start_index, length);
+                assert_eq!(removed.chars().count() + sub.chars().count(), chars.len());
+                assert!((0..=removed.len())
+                    .filter(|&i| removed.is_char_boundary(i))
+                    .any(|i| str_concat!(&removed[..i], &sub, &removed[i..]) == s));
+                assert_eq!(str_remove_cow(s, start_index, length), removed);
+            }
+        }
+
+        for start_index in unsigned {
+            for length in unsigned {
+                let start: usize = start_index.min(chars.len());
+                let end: usize = start_index.saturating_add(length).min(chars.len());
+                let expected: String = chars[..start].iter().chain(&chars[end..]).collect();
+                assert_eq!(str_removeu(s, start_index, length), expected);
+            }
+        }
+    }
+}
+
+// -----------------------------------------------------------------------------
+
+#[test]
+fn test_str_insert_splice() {
+    let s1: &str = "test éèçà 123";
+    let s2: String = s1.to_owned();
+    let index: CharIndex = CharIndex::new(s1);
+
+    // str_insert
+    assert_eq!(str_insert("test 123", 5, "éè "), "test éè 123");
+    assert_eq!(str_insert("test 123", -3, "éè "), "test éè 123");
+    assert_eq!(str_insert("test", 0, "!"), "!test");
+    assert_eq!(str_insert("test", 4, "!"), "test!");
+    assert_eq!(str_insert("test", 100, "!"), "test!");
+    assert_eq!(str_insert("test", -100, "!"), "!test");
+    assert_eq!(str_insert("test", isize::MIN, "!"), "!test");
+    assert_eq!(str_insert("", 3, "éè"), "éè");
+    assert_eq!(str_insert("éè", 1, ""), "éè");
+
+    // str_splice
+    assert_eq!(str_splice(s1, 5, 4, "abc"), "test abc 123");
+    assert_eq!(str_splice(s1, -3, 3, "456"), "test éèçà 456");
+    assert_eq!(str_splice(s1, 5, 100, ""), "test ");
+    assert_eq!(str_splice(s1, 5, isize::MAX, "x"), "test x");
+    assert_eq!(str_splice(s1, 5, -2, "x"), "test xéèçà 123"); // Negative delete_count removes nothing
+    assert_eq!(str_splice(s1, 0, 0, ""), s1);
+    assert_eq!(str_splice(s1, 100, 2, "!"), "test éèçà 123!");
+    assert_eq!(str_splice(s1, -100, 4, "\u{1F600}"), "\u{1F600} éèçà 123");
+    assert_eq!(str_splice("", -1, 1, "a"), "a");
+
+    // Methods
+    assert_eq!(s1.str_insert(-4, "!"), "test éèçà! 123");
+    assert_eq!(s2.str_insert(-4, "!"), "test éèçà! 123");
+    assert_eq!(s1.str_splice(6, 2, "_"), "test é_à 123");
+    assert_eq!(s2.str_splice(6, 2, "_"), "test é_à 123");
+    for start in [isize::MIN, -14, -13, -5, -1, 0, 1, 5, 12, 13, 14, isize::MAX] {
+        for delete_count in [isize::MIN, -1, 0, 1, 3, 13, isize::MAX] {
+            assert_eq!(index.str_splice(start, delete_count, "àç"), str_splice(s1, start, delete_count, "àç"));
+        }
+        assert_eq!(index.str_insert(start, "àç"), str_insert(s1, start, "àç"));
+    }
+}
+
+// -----------------------------------------------------------------------------
+
+#[test]
+fn test_slices() {
+    let s1: &str = "Test 123 éèçà 123 test home";
+    let s2: String = s1.to_owned();
+
+    assert_eq!(substr_slice(s1, 9, 4), "éèçà");
+    assert_eq!(substr_slice(s1, -4, 4), "home");
+    assert_eq!(substr_slice(s1, 12, -3), "èçà");
+    assert_eq!(substr_slice(s1, 9, isize::MAX), "éèçà 123 test home");
+    assert_eq!(substr_slice(s1, 100, 1), "");
+    assert_eq!(substr_slice("", 0, 1), "");
+
+    assert_eq!(substru_slice(s1, 9, 4), "éèçà");
+    assert_eq!(substru_slice(s1, 23, 100), "home");
+    assert_eq!(substru_slice(s1, 100, 1), "");
+    assert_eq!(substru_slice(s1, 1, usize::MAX), "est 123 éèçà 123 test home");
+
+    assert_eq!(substr_end_slice(s1, 18), "test home");
+    assert_eq!(substr_end_slice(s1, -9), "test home");
+    assert_eq!(substr_end_slice(s1, 27), "");
+    assert_eq!(substr_end_slice(s1, -28), "");
+
+    assert_eq!(substring_slice(s1, 9, 13), "éèçà");
+    assert_eq!(substring_slice(s1, 13, 9), "éèçà"); // Swap
+    assert_eq!(substring_slice(s1, 23, 100), "home");
+    assert_eq!(substring_slice(s1, -5, -2), "");
+    assert_eq!(substring_slice(s1, 9, 9), "");
+
+    // The result is a slice of the original string
+    let slice: &str = substr_slice(s1, 9, 4);
+    assert_eq!(slice.as_ptr(), s1[9..].as_ptr());
+
+    // str
+    assert_eq!(s1.substr_slice(9, 4), "éèçà");
+    assert_eq!(s1.substru_slice(9, 4), "éèçà");
+    assert_eq!(s1.substr_end_slice(-9), "test home");
+    assert_eq!(s1.substring_slice(9, 13), "éèçà");
+
+    // String
+    assert_eq!(s2.substr_slice(9, 4), "éèçà");
+    assert_eq!(s2.substru_slice(9, 4), "éèçà");
+    assert_eq!(s2.substr_end_slice(-9), "test home");
+    assert_eq!(s2.substring_slice(9, 13), "éèçà");
+}
+
+// -----------------------------------------------------------------------------
+
+#[test]
+#[allow(clippy::reversed_empty_ranges)] // 5..-4 is not empty with negative bounds
+fn test_char_slice() {
+    let s1: &str = "test éèçà 123";
+    let s2: String = s1.to_owned();
+    let index: CharIndex = CharIndex::new(s1);
+
+    assert_eq!(char_slice(s1, 5..9), "éèçà");
+    assert_eq!(char_slice(s1, 5..=8), "éèçà");
+    assert_eq!(char_slice(s1, ..4), "test");
+    assert_eq!(char_slice(s1, ..=3), "test");
+    assert_eq!(char_slice(s1, 10..), "123");
+    assert_eq!(char_slice(s1, ..), s1);
+    assert_eq!(char_slice(s1, 10..100), "123");
+    assert_eq!(char_slice(s1, 100..), "");
+    assert_eq!(char_slice(s1, 5..5), "");
+    assert_eq!(char_slice(s1, 0..usize::MAX), s1);
+    assert_eq!(char_slice(s1, ..=usize::MAX), s1);
+    assert_eq!(char_slice("", 0..1), "");
+
+    assert_eq!(char_slice_signed(s1, -3..), "123");
+    assert_eq!(char_slice_signed(s1, ..-4), "test éèçà");
+    assert_eq!(char_slice_signed(s1, 5..-4), "éèçà");
+    assert_eq!(char_slice_signed(s1, -8..=-5), "éèçà");
+    assert_eq!(char_slice_signed(s1, -1..), "3");
+    assert_eq!(char_slice_signed(s1, ..=-1), s1);
+    assert_eq!(char_slice_signed(s1, -100..4), "test");
+    assert_eq!(char_slice_signed(s1, ..=-100), "");
+    assert_eq!(char_slice_signed(s1, 10..100), "123");
+    assert_eq!(char_slice_signed(s1, isize::MIN..isize::MAX), s1);
+    assert_eq!(char_slice_signed(s1, -3..-5), "");
+    assert_eq!(char_slice_signed("", -1..), "");
+
+    // Same results as Python slicing s[start:stop]
+    let chars: Vec<char> = s1.chars().collect();
+    let n: isize = chars.len() as isize;
+    for start in -15..15 {
+        for end in -15..15 {
+            let py = |i: isize| if i < 0 { (n + i).max(0) } else { i.min(n) } as usize;
+            let (a, b) = (py(start), py(end));
+            let expected: String = if a < b { chars[a..b].iter().collect() } else { String::new() };
+            assert_eq!(char_slice_signed(s1, start..end), expected);
+            assert_eq!(index.char_slice_signed(start..end), expected);
+            if end != -1 {
+                assert_eq!(char_slice_signed(s1, start..=end), char_slice_signed(s1, start..end + 1));
+            }
+        }
+    }
+
+    // Methods
+    assert_eq!(s1.char_slice(2..5), "st ");
+    assert_eq!(s2.char_slice(2..5), "st ");
+    assert_eq!(index.char_slice(2..5), "st ");
+    assert_eq!(index.char_slice(100..), "");
+    assert_eq!(s1.char_slice_signed(-3..), "123");
+    assert_eq!(s2.char_slice_signed(-3..), "123");
+    assert_eq!(index.char_slice_signed(-3..), "123");
+}
+
+// -----------------------------------------------------------------------------
+
+#[test]
+fn test_py_slice() {
+    let s1: &str = "0123456789";
+    let s2: &str = "aé€\u{1F600}z";
+
+    assert_eq!(py_slice(s1, None, None, 1), s1);
+    assert_eq!(py_slice(s1, None, None, -1), "9876543210");
+    assert_eq!(py_slice(s1, Some(1), Some(9), 2), "1357");
+    assert_eq!(py_slice(s1, Some(-5), None, -1), "543210");
+    assert_eq!(py_slice(s1, Some(-3), None, 1), "789");
+    assert_eq!(py_slice(s1, None, Some(-3), 1), "0123456");
+    assert_eq!(py_slice(s1, Some(8), Some(2), -2), "864");
+    assert_eq!(py_slice(s1, Some(2), Some(8), -1), "");
+    assert_eq!(py_slice(s1, Some(100), None, -1), "9876543210");
+    assert_eq!(py_slice(s1, Some(-100), None, 1), s1);
+    assert_eq!(py_slice(s1, None, Some(-100), -1), "9876543210");
+    assert_eq!(py_slice(s1, Some(isize::MIN), Some(isize::MAX), isize::MAX), "0");
+    assert_eq!(py_slice(s1, Some(isize::MAX), Some(isize::MIN), isize::MIN), "9");
+    assert_eq!(py_slice(s2, None, None, -1), "z\u{1F600}€éa");
+    assert_eq!(py_slice(s2, Some(1), None, 2), "é\u{1F600}");
+    assert_eq!(py_slice("", None, None, -1), "");
+
+    // CPython slice.indices reference
+    let chars: Vec<char> = s2.chars().collect();
+    let n: isize = chars.len() as isize;
+    let values: [Option<isize>; 10] = [None, Some(-7), Some(-5), Some(-2), Some(-1), Some(0), Some(1), Some(4), Some(5), Some(9)];
+    for step in [-6, -2, -1, 1, 2, 6] {
+        for start in values {
+            for stop in values {
+                let (lower, upper) = if step < 0 { (-1, n - 1) } else { (0, n) };
+                let clamp = |i: isize| if i < 0 { (i + n).max(lower) } else { i.min(upper) };
+                let mut i: isize = start.map_or(if step < 0 { upper } else { lower }, clamp);
+                let stop_i: isize = stop.map_or(if step < 0 { lower } else { upper }, clamp);
+                let mut expected: String = String::new();
+                while (step > 0 && i < stop_i) || (step < 0 && i > stop_i) {
+                    expected.push(chars[i as usize]);
+                    i += step;
+                }
+                assert_eq!(py_slice(s2, start, stop, step), expected);
+            }
+        }
+    }
+
+    // Macro
+    assert_eq!(pyslice!(s1, [::-1]), "9876543210");
+    assert_eq!(pyslice!(s1, [1:-1:2]), "1357");
+    assert_eq!(pyslice!(s1, [-3:]), "789");
+    assert_eq!(pyslice!(s1, [:3]), "012");
+    assert_eq!(pyslice!(s1, [:]), s1);
+    assert_eq!(pyslice!(s1, [::]), s1);
+    assert_eq!(pyslice!(s1, [::2]), "02468");
+    assert_eq!(pyslice!(s1, [-5::-1]), "543210");
+    assert_eq!(pyslice!(s1, [1:3:]), "12");
+    assert_eq!(pyslice!(s1, [:-8:-1]), "9876543");
+    let s3: String = s2.to_owned();
+    assert_eq!(pyslice!(&s3, [1:]), "é€\u{1F600}z");
+}
+
+#[test]
+fn test_py_slice_step_zero() {
+    // ValueError in Python
+    assert_eq!(py_slice("0123456789", None, None, 0), "");
+    assert_eq!(py_slice("0123456789", Some(1), Some(5), 0), "");
+    assert_eq!(pyslice!("0123456789", [::0]), "");
+}
+
+// -----------------------------------------------------------------------------
+
+#[test]
+fn test_str_remove_matches() {
+    let s1: &str = "Test 123 éèçà 123, test; home";
+    let s2: String = s1.to_owned();
+
+    assert_eq!(str_remove_matches(s1, "123"), "Test  éèçà , test; home");
+    assert_eq!(str_remove_matches(s1, [',', ';']), "Test 123 éèçà 123 test home");
+    assert_eq!(str_remove_matches(s1, |c: char| c.is_ascii_digit()), "Test  éèçà , test; home");
+    assert_eq!(str_remove_matches(s1, |c: char| !c.is_ascii()), "Test 123  123, test; home");
+    assert_eq!(str_remove_matches(s1, 'é'), "Test 123 èçà 123, test; home");
+    assert_eq!(str_remove_matches(s1, ""), s1); // Remove nothing
+    assert_eq!(str_remove_matches(s1, "not found"), s1);
+    assert_eq!(str_remove_matches("aaa", "aa"), "a");
+    assert_eq!(str_remove_matches("", 'a'), "");
+
+    assert_eq!(s1.str_remove_matches([',', ';']), "Test 123 éèçà 123 test home");
+    assert_eq!(s2.str_remove_matches([',', ';']), "Test 123 éèçà 123 test home");
+    assert_eq!(CharIndex::new(s1).str_remove_matches([',', ';']), "Test 123 éèçà 123 test home");
+}
+
+// -----------------------------------------------------------------------------
+
+#[test]
+fn test_str_remove_cow() {
+    let s1: &str = "Test 123 éèçà 123 test home";
+    let s2: String = s1.to_owned();
+
+    assert_eq!(str_remove_cow(s1, 14, 4), "Test 123 éèçà test home");
+    assert_eq!(str_remove_cow(s1, 9, 5), "Test 123 123 test home");
+    assert_eq!(str_remove_cow(s1, 3, 100), "Tes");
+    assert_eq!(str_remove_cow("", 0, 1), "");
+
+    assert!(matches!(str_remove_cow(s1, 14, 4), Cow::Owned(_)));
+    assert!(matches!(str_remove_cow(s1, 0, 0), Cow::Borrowed(_))); // Remove nothing
+    assert!(matches!(str_remove_cow(s1, 27, 2), Cow::Borrowed(_))); // Out of bounds
+    assert!(matches!(str_remove_cow(s1, isize::MAX, isize::MAX), Cow::Borrowed(_)));
+    assert!(matches!(str_remove_cow(s1, isize::MIN, isize::MIN), Cow::Borrowed(_)));
+
+    assert_eq!(s1.str_remove_cow(14, 4), "Test 123 éèçà test home");
+    assert_eq!(s2.str_remove_cow(14, 4), "Test 123 éèçà test home");
+}
+
+// -----------------------------------------------------------------------------
+
+#[test]
+fn test_try_functions() {
+    let s1: &str = "0123456789";
+    let s2: String = "éèçà 56789".to_owned();
+    let index: CharIndex = CharIndex::new(&s2);
+
+    // try_substr
+    assert_eq!(try_substr(s1, 2, 3), Ok("234".to_owned()));
+    assert_eq!(try_substr(s1, -3, 3), Ok("789".to_owned()));
+    assert_eq!(try_substr(s1, 5, -3), Ok("345".to_owned()));
+    assert_eq!(try_substr(s1, 0, -1), Ok("0".to_owned()));
+    assert_eq!(try_substr(s1, 10, 0), Ok("".to_owned()));
+    assert_eq!(try_substr(s1, 8, 3), Err(StrIndexError::Length { length: 3, char_len: 10 }));
+    assert_eq!(try_substr(s1, 1, -3), Err(StrIndexError::Length { length: -3, char_len: 10 }));
+    assert_eq!(try_substr(s1, 10, 1), Err(StrIndexError::StartIndex { index: 10, char_len: 10 }));
+    assert_eq!(try_substr(s1, -11, 1), Err(StrIndexError::StartIndex { index: -11, char_len: 10 }));
+    assert_eq!(try_substr(s1, 2, isize::MAX), Err(StrIndexError::Length { length: isize::MAX, char_len: 10 }));
+    assert_eq!(try_substr(s1, isize::MIN, 1), Err(StrIndexError::StartIndex { index: isize::MIN, char_len: 10 }));
+    assert_eq!(try_substr("", 0, 0), Ok("".to_owned()));
+    assert_eq!(try_substr("", 0, 1), Err(StrIndexError::StartIndex { index: 0, char_len: 0 }));
+
+    // try_substru
+    assert_eq!(try_substru(&s2, 0, 4), Ok("éèçà".to_owned()));
+    assert_eq!(try_substru(&s2, 10, 0), Ok("".to_owned()));
+    assert_eq!(try_substru(&s2, 11, 0), Err(StrIndexError::StartIndex { index: 11, char_len: 10 }));
+    assert_eq!(try_substru(&s2, 2, usize::MAX), Err(StrIndexError::Length { length: isize::MAX, char_len: 10 }));
+
+    // try_substr_end
+    assert_eq!(try_substr_end(&s2, 5), Ok("56789".to_owned()));
+    assert_eq!(try_substr_end(&s2, -7), Ok("à 56789".to_owned()));
+    assert_eq!(try_substr_end(&s2, 10), Ok("".to_owned()));
+    assert_eq!(try_substr_end(&s2, 11), Err(StrIndexError::StartIndex { index: 11, char_len: 10 }));
+    assert_eq!(try_substr_end(&s2, -11), Err(StrIndexError::StartIndex { index: -11, char_len: 10 }));
+
+    // try_substring
+    assert_eq!(try_substring(s1, 2, 5), Ok("234".to_owned()));
+    assert_eq!(try_substring(s1, 5, 2), Ok("234".to_owned()));
+    assert_eq!(try_substring(s1, 0, 10), Ok(s1.to_owned()));
+    assert_eq!(try_substring(s1, -1, 5), Err(StrIndexError::StartIndex { index: -1, char_len: 10 }));
+    assert_eq!(try_substring(s1, 2, 11), Err(StrIndexError::EndIndex { index: 11, char_len: 10 }));
+
+    // try_str_remove
+    assert_eq!(try_str_remove(&s2, 1, 2), Ok("éà 56789".to_owned()));
+    assert_eq!(try_str_remove(&s2, 10, 0), Ok(s2.clone()));
+    assert_eq!(try_str_remove(&s2, 8, 3), Err(StrIndexError::Length { length: 3, char_len: 10 }));
+    assert_eq!(try_str_remove("", 1, 0), Err(StrIndexError::StartIndex { index: 1, char_len: 0 }));
+
+    // Methods
+    assert_eq!(s1.try_substr(2, 3), Ok("234".to_owned()));
+    assert_eq!(s2.try_substring(0, 4), Ok("éèçà".to_owned()));
+    assert_eq!(s2.try_substr_end(11), Err(StrIndexError::StartIndex { index: 11, char_len: 10 }));
+    assert_eq!(index.try_substr(1, 3), Ok("èçà".to_owned()));
+    assert_eq!(index.try_substr(-1, -4), Ok("6789".to_owned()));
+    assert_eq!(index.try_substru(4, 7), Err(StrIndexError::Length { length: 7, char_len: 10 }));
+    assert_eq!(index.try_substr_end(-5), Ok("56789".to_owned()));
+    assert_eq!(index.try_substring(10, 4), Ok(" 56789".to_owned()));
+    assert_eq!(index.try_str_remove(0, 5), Ok("56789".to_owned()));
+
+    // Display
+    assert_eq!(
+        StrIndexError::Length { length: 3, char_len: 10 }.to_string(),
+        "length 3 out of range for a string of 10 characters"
+    );
+}
+
+// -----------------------------------------------------------------------------
+
+#[test]
+fn test_char_string_mut() {
+    let mut s: String = "test éèçà 123".to_owned();
+    s.remove_chars(4, 5);
+    assert_eq!(s, "test 123");
+    s.insert_at_char(5, "àç ");
+    assert_eq!(s, "test àç 123");
+    s.replace_char_range(0..4, "a");
+    assert_eq!(s, "a àç 123");
+    s.replace_char_range(2..=3, "\u{1F600}");
+    assert_eq!(s, "a \u{1F600} 123");
+    s.truncate_chars(5);
+    assert_eq!(s, "a \u{1F600} 1");
+    s.retain_char_range(2..);
+    assert_eq!(s, "\u{1F600} 1");
+
+    // Out of bounds: never panics
+    let mut s: String = "éèçà".to_owned();
+    s.remove_chars(4, 1);
+    s.remove_chars(usize::MAX, usize::MAX);
+    s.remove_chars(1, 0);
+    assert_eq!(s, "éèçà");
+    s.remove_chars(2, usize::MAX);
+    assert_eq!(s, "éè");
+    s.insert_at_char(100, "à");
+    assert_eq!(s, "éèà");
+    s.insert_at_char(0, "ç");
+    assert_eq!(s, "çéèà");
+    s.replace_char_range(10..20, "!");
+    assert_eq!(s, "çéèà!");
+    let (start, end): (usize, usize) = (3, 1);
+    s.replace_char_range(start..end, "_"); // End before start: empty range, insert
+    assert_eq!(s, "çéè_à!");
+    s.replace_char_range(.., "abc");
+    assert_eq!(s, "abc");
+    s.truncate_chars(10);
+    assert_eq!(s, "abc");
+    s.retain_char_range(1..usize::MAX);
+    assert_eq!(s, "bc");
+    s.retain_char_range(5..);
+    assert_eq!(s, "");
+    s.truncate_chars(0);
+    s.retain_char_range(..);
+    s.remove_chars(0, 1);
+    assert_eq!(s, "");
+
+    // In place: no reallocation
+    let mut s: String = String::with_capacity(64);
+    s.push_str("test éèçà 123");
+    let ptr = s.as_ptr();
+    s.remove_chars(0, 5);
+    s.insert_at_char(4, " and ");
+    s.replace_char_range(..4, "àç");
+    s.retain_char_range(1..);
+    s.truncate_chars(3);
+    assert_eq!(s, "ç a");
+    assert_eq!(s.as_ptr(), ptr);
+}
+
+// -----------------------------------------------------------------------------
+
+#[test]
+fn test_char_index() {
+    let s1: String = "Test 123 éèçà 123 test home ".repeat(10); // 280 characters
+    let index: CharIndex = CharIndex::new(&s1);
+
+    assert_eq!(index.as_str(), s1);
+    assert_eq!(index.char_count(), 280);
+    assert_eq!(index.byte_index(0), 0);
+    assert_eq!(index.byte_index(10), 11); // After "é"
+    assert_eq!(index.byte_index(280), s1.len());
+    assert_eq!(index.byte_index(usize::MAX), s1.len());
+    assert_eq!(index.char_index(0), 0);
+    assert_eq!(index.char_index(11), 10);
+    assert_eq!(index.char_index(12), 10); // Inside "è"
+    assert_eq!(index.char_index(s1.len()), 280);
+
+    for (char_pos, (byte_pos, _)) in s1.char_indices().enumerate() {
+        assert_eq!(index.byte_index(char_pos), byte_pos);
+        assert_eq!(index.char_index(byte_pos), char_pos);
+    }
+
+    // Same results as the str methods
+    for start in [-300, -281, -280, -50, -1, 0, 1, 9, 31, 32, 33, 64, 279, 280, 300] {
+        for length in [isize::MIN, -40, -1, 0, 1, 4, 40, isize::MAX] {
+            assert_eq!(index.substr(start, length), s1.substr(start, length));
+            assert_eq!(index.substring(start, length), s1.substring(start, length));
+            assert_eq!(index.str_remove(start, length), s1.str_remove(start, length));
+        }
+        assert_eq!(index.substr_end(start), s1.substr_end(start));
+        if start >= 0 {
+            let start: usize = start as usize;
+            assert_eq!(index.indexof("test", start), s1.indexof("test", start));
+            assert_eq!(index.indexof("éèçà", start), s1.indexof("éèçà", start));
+            assert_eq!(index.substru(start, 40), s1.substru(start, 40));
+            assert_eq!(index.str_removeu(start, 40), s1.str_removeu(start, 40));
+        }
+    }
+
+    assert_eq!(index.substr_slice(9, 4), "éèçà");
+    assert_eq!(index.substru_slice(37, 4), "éèçà");
+    assert_eq!(index.substr_end_slice(-5), "home ");
+    assert_eq!(index.substring_slice(65, 69), "éèçà");
+    assert_eq!(index.str_remove_cow(0, 270), "test home ");
+    assert!(matches!(index.str_remove_cow(280, 1), Cow::Borrowed(_)));
+    assert_eq!(index.indexof("", 0), None);
+
+    let empty: CharIndex = CharIndex::from("");
+    assert_eq!(empty.char_count(), 0);
+    assert_eq!(empty.substr(0, 1), "");
+    assert_eq!(empty.indexof("a", 0), None);
+    assert_eq!(empty.str_remove(0, 1), "");
 }
 
 // -----------------------------------------------------------------------------
@@ -326,3 +1212,715 @@ fn test_str_concat() {
 }
 
 // -----------------------------------------------------------------------------
+
+#[test]
+fn test_graphemes() {
+    // e + combining acute, a, family (ZWJ sequence), thumbs up with skin tone, flags BE and FR
+    let s1: &str = "e\u{301}a\u{1F468}\u{200D}\u{1F469}\u{200D}\u{1F467}\u{1F44D}\u{1F3FD}\u{1F1E7}\u{1F1EA}\u{1F1EB}\u{1F1F7}";
+
+    assert_eq!(
+        grapheme::graphemes(s1).collect::<Vec<&str>>(),
+        [
+            "e\u{301}",
+            "a",
+            "\u{1F468}\u{200D}\u{1F469}\u{200D}\u{1F467}",
+            "\u{1F44D}\u{1F3FD}",
+            "\u{1F1E7}\u{1F1EA}",
+            "\u{1F1EB}\u{1F1F7}"
+        ]
+    );
+    assert_eq!(grapheme::grapheme_count(s1), 6);
+    assert_eq!(s1.grapheme_count(), 6);
+    assert_eq!(s1.to_owned().grapheme_count(), 6);
+    assert_eq!(grapheme::grapheme_count(""), 0);
+
+    assert_eq!(grapheme::grapheme_count("\r\n"), 1); // CR LF
+    assert_eq!(grapheme::grapheme_count("\n\r"), 2);
+    assert_eq!(grapheme::grapheme_count("\u{1112}\u{1161}\u{11AB}"), 1); // Hangul L V T
+    assert_eq!(grapheme::grapheme_count("\u{D55C}\u{AE00}"), 2); // Hangul syllables LVT LVT
+    assert_eq!(grapheme::grapheme_count("\u{915}\u{93F}"), 1); // Devanagari spacing mark
+    assert_eq!(grapheme::grapheme_count("\u{600}1"), 1); // Prepend
+    assert_eq!(grapheme::grapheme_count("\u{1F1E7}\u{1F1EA}\u{1F1EB}"), 2); // Odd number of regional indicators
+    assert_eq!(grapheme::grapheme_count("a\u{200D}\u{1F469}"), 2); // ZWJ not after an emoji
+    assert_eq!(grapheme::grapheme_count("\u{301}"), 1); // Combining mark at the start
+}
+
+// -----------------------------------------------------------------------------
+
+#[test]
+fn test_grapheme_substr() {
+    let s1: &str = "ae\u{301}b\u{1F44D}\u{1F3FD}c"; // a é b 👍🏽 c
+    let s2: String = s1.to_owned();
+
+    assert_eq!(grapheme::substr(s1, 1, 1), "e\u{301}");
+    assert_eq!(grapheme::substr(s1, 0, 3), "ae\u{301}b");
+    assert_eq!(grapheme::substr(s1, -2, 1), "\u{1F44D}\u{1F3FD}");
+    assert_eq!(grapheme::substr(s1, -1, -2), "\u{1F44D}\u{1F3FD}c");
+    assert_eq!(grapheme::substr(s1, 2, isize::MAX), "b\u{1F44D}\u{1F3FD}c");
+    assert_eq!(grapheme::substr(s1, 5, 1), ""); // Out of bounds
+    assert_eq!(grapheme::substr(s1, -6, 1), ""); // Out of bounds
+    assert_eq!(grapheme::substr(s1, 0, 0), "");
+    assert_eq!(grapheme::substr("", 0, 1), "");
+
+    assert_eq!(grapheme::substru(s1, 1, 2), "e\u{301}b");
+    assert_eq!(grapheme::substru(s1, 4, 100), "c");
+    assert_eq!(grapheme::substru(s1, usize::MAX, 1), "");
+
+    assert_eq!(grapheme::substr_end(s1, 3), "\u{1F44D}\u{1F3FD}c");
+    assert_eq!(grapheme::substr_end(s1, -4), "e\u{301}b\u{1F44D}\u{1F3FD}c");
+    assert_eq!(grapheme::substr_end(s1, 5), "");
+    assert_eq!(grapheme::substr_end(s1, -6), "");
+
+    assert_eq!(grapheme::substring(s1, 1, 3), "e\u{301}b");
+    assert_eq!(grapheme::substring(s1, 3, 1), "e\u{301}b"); // Swap
+    assert_eq!(grapheme::substring(s1, -5, 2), "ae\u{301}");
+    assert_eq!(grapheme::substring(s1, 3, 100), "\u{1F44D}\u{1F3FD}c");
+    assert_eq!(grapheme::substring(s1, 2, 2), "");
+
+    // str
+    assert_eq!(s1.grapheme_substr(1, 1), "e\u{301}");
+    assert_eq!(s1.grapheme_substru(1, 2), "e\u{301}b");
+    assert_eq!(s1.grapheme_substr_end(3), "\u{1F44D}\u{1F3FD}c");
+    assert_eq!(s1.grapheme_substring(1, 3), "e\u{301}b");
+
+    // String
+    assert_eq!(s2.grapheme_substr(1, 1), "e\u{301}");
+    assert_eq!(s2.grapheme_substru(1, 2), "e\u{301}b");
+    assert_eq!(s2.grapheme_substr_end(3), "\u{1F44D}\u{1F3FD}c");
+    assert_eq!(s2.grapheme_substring(1, 3), "e\u{301}b");
+}
+
+// -----------------------------------------------------------------------------
+
+#[test]
+fn test_grapheme_indexof() {
+    let s1: &str = "Cafe\u{301} cafe \u{1F44D}\u{1F3FD} cafe\u{301}";
+    let s2: String = s1.to_owned();
+
+    assert_eq!(grapheme::indexof(s1, "cafe", 0), Some(5)); // "Cafe\u{301}" doesn't end on a boundary
+    assert_eq!(grapheme::indexof(s1, "cafe\u{301}", 0), Some(12));
+    assert_eq!(grapheme::indexof(s1, "\u{1F44D}", 0), None); // Only part of a grapheme
+    assert_eq!(grapheme::indexof(s1, "\u{1F44D}\u{1F3FD}", 0), Some(10));
+    assert_eq!(grapheme::indexof(s1, "e\u{301}", 0), Some(3));
+    assert_eq!(grapheme::indexof(s1, "e\u{301}", 4), Some(15));
+    assert_eq!(grapheme::indexof(s1, "e\u{301}", 16), None); // Out of bounds
+    assert_eq!(grapheme::indexof(s1, "cafe", 100), None); // Out of bounds
+    assert_eq!(grapheme::indexof(s1, "", 0), None); // Search nothing
+
+    assert_eq!(s1.grapheme_indexof("cafe", 0), Some(5));
+    assert_eq!(s2.grapheme_indexof("cafe", 0), Some(5));
+}
+
+// -----------------------------------------------------------------------------
+
+#[test]
+fn test_grapheme_str_remove() {
+    let s1: &str = "ae\u{301}b\u{1F44D}\u{1F3FD}c";
+    let s2: String = s1.to_owned();
+
+    assert_eq!(grapheme::str_remove(s1, 1, 1), "ab\u{1F44D}\u{1F3FD}c");
+    assert_eq!(grapheme::str_remove(s1, 3, 100), "ae\u{301}b");
+    assert_eq!(grapheme::str_remove(s1, 0, 0), s1); // Remove nothing
+    assert_eq!(grapheme::str_remove(s1, 5, 1), s1); // Out of bounds
+    assert_eq!(grapheme::str_remove(s1, 1, isize::MAX), "a");
+    assert_eq!(grapheme::str_remove("", 0, 1), "");
+
+    // Negative values, same graphemes as grapheme::substr
+    assert_eq!(grapheme::str_remove(s1, -3, 2), "ae\u{301}c");
+    assert_eq!(grapheme::str_remove(s1, -1, 1), "ae\u{301}b\u{1F44D}\u{1F3FD}");
+    assert_eq!(grapheme::str_remove(s1, 3, -2), "ae\u{301}c");
+    assert_eq!(grapheme::str_remove(s1, 2, -5), "\u{1F44D}\u{1F3FD}c");
+    assert_eq!(grapheme::str_remove(s1, -10, 2), s1); // Out of bounds
+    assert_eq!(grapheme::str_remove(s1, isize::MIN, isize::MIN), s1);
+    for start in -6..6 {
+        for length in -6..6 {
+            let removed: String = grapheme::substr(s1, start, length);
+            let kept: String = grapheme::str_remove(s1, start, length);
+            assert_eq!(kept.len() + removed.len(), s1.len(), "start {start}, length {length}");
+        }
+    }
+    assert_eq!(s1.grapheme_str_remove(-2, 1), "ae\u{301}bc");
+
+    assert_eq!(s1.grapheme_str_remove(1, 1), "ab\u{1F44D}\u{1F3FD}c");
+    assert_eq!(s2.grapheme_str_remove(1, 1), "ab\u{1F44D}\u{1F3FD}c");
+}
+
+// -----------------------------------------------------------------------------
+
+#[test]
+fn test_js_slice() {
+    let s1: &str = "0123456789";
+    let s2: &str = "a\u{1F600}b"; // UTF-16: a, D83D, DE00, b
+
+    assert_eq!(js::length(s1), 10);
+    assert_eq!(js::length(s2), 4);
+    assert_eq!(js::length(""), 0);
+
+    // slice
+    assert_eq!(js::slice(s1, 2, Some(-2)), "234567");
+    assert_eq!(js::slice(s1, -3, None), "789");
+    assert_eq!(js::slice(s1, 5, Some(2)), "");
+    assert_eq!(js::slice(s1, -100, Some(100)), s1);
+    assert_eq!(js::slice(s1, isize::MIN, Some(isize::MAX)), s1);
+    assert_eq!(js::slice(s2, 1, Some(3)), "\u{1F600}");
+    assert_eq!(js::slice(s2, 2, None), "\u{FFFD}b"); // Lone surrogate
+
+    // substr
+    assert_eq!(js::substr(s1, 2, Some(3)), "234");
+    assert_eq!(js::substr(s1, -3, Some(2)), "78");
+    assert_eq!(js::substr(s1, 2, Some(-1)), "");
+    assert_eq!(js::substr(s1, 8, None), "89");
+    assert_eq!(js::substr(s1, 20, Some(2)), "");
+    assert_eq!(js::substr(s1, -20, Some(2)), "01");
+    assert_eq!(js::substr(s1, 1, Some(isize::MAX)), "123456789");
+    assert_eq!(js::substr(s2, 1, Some(2)), "\u{1F600}");
+
+    // substring
+    assert_eq!(js::substring(s1, 2, Some(5)), "234");
+    assert_eq!(js::substring(s1, 5, Some(2)), "234");
+    assert_eq!(js::substring(s1, -5, Some(2)), "01");
+    assert_eq!(js::substring(s1, 7, None), "789");
+    assert_eq!(js::substring(s2, 3, Some(1)), "\u{1F600}");
+
+    // at, charAt, charCodeAt, codePointAt
+    assert_eq!(js::at(s1, 0), Some("0".to_owned()));
+    assert_eq!(js::at(s1, -1), Some("9".to_owned()));
+    assert_eq!(js::at(s1, -11), None);
+    assert_eq!(js::at(s1, 10), None);
+    assert_eq!(js::at(s2, 1), Some("\u{FFFD}".to_owned()));
+    assert_eq!(js::char_at(s1, 3), "3");
+    assert_eq!(js::char_at(s1, -1), "");
+    assert_eq!(js::char_at(s1, 10), "");
+    assert_eq!(js::char_code_at(s2, 0), Some(0x61));
+    assert_eq!(js::char_code_at(s2, 1), Some(0xD83D));
+    assert_eq!(js::char_code_at(s2, 2), Some(0xDE00));
+    assert_eq!(js::char_code_at(s2, 4), None);
+    assert_eq!(js::char_code_at(s2, -1), None);
+    assert_eq!(js::code_point_at(s2, 1), Some(0x1F600));
+    assert_eq!(js::code_point_at(s2, 2), Some(0xDE00));
+    assert_eq!(js::code_point_at(s2, 3), Some(0x62));
+    assert_eq!(js::code_point_at(s2, 4), None);
+    assert_eq!(js::code_point_at("é", 0), Some(0xE9));
+}
+
+#[test]
+fn test_js_search() {
+    let s1: &str = "a\u{1F600}b\u{1F600}"; // UTF-16 indexes: a 0, 😀 1, b 3, 😀 4
+
+    assert_eq!(js::index_of(s1, "\u{1F600}", 0), Some(1));
+    assert_eq!(js::index_of(s1, "\u{1F600}", 2), Some(4));
+    assert_eq!(js::index_of(s1, "b", -5), Some(3));
+    assert_eq!(js::index_of(s1, "c", 0), None);
+    assert_eq!(js::index_of(s1, "", 2), Some(2));
+    assert_eq!(js::index_of(s1, "", 100), Some(6));
+    assert_eq!(js::index_of("", "", 0), Some(0));
+    assert_eq!(js::index_of("", "a", 0), None);
+
+    assert_eq!(js::last_index_of("canal", "a", None), Some(3));
+    assert_eq!(js::last_index_of("canal", "a", Some(2)), Some(1));
+    assert_eq!(js::last_index_of("canal", "a", Some(0)), None);
+    assert_eq!(js::last_index_of("canal", "c", Some(-5)), Some(0));
+    assert_eq!(js::last_index_of("canal", "x", None), None);
+    assert_eq!(js::last_index_of("canal", "", None), Some(5));
+    assert_eq!(js::last_index_of("canal", "", Some(2)), Some(2));
+    assert_eq!(js::last_index_of("canal", "canals", None), None);
+    assert_eq!(js::last_index_of(s1, "\u{1F600}", None), Some(4));
+    assert_eq!(js::last_index_of(s1, "\u{1F600}", Some(3)), Some(1));
+
+    assert!(js::includes(s1, "b\u{1F600}", 0));
+    assert!(!js::includes(s1, "b", 4));
+    assert!(js::includes(s1, "", 100));
+}
+
+#[test]
+fn test_js_pad_split() {
+    assert_eq!(js::pad_start("5", 3, Some("0")), "005");
+    assert_eq!(js::pad_start("abc", 10, Some("123")), "1231231abc");
+    assert_eq!(js::pad_start("abc", 6, None), "   abc");
+    assert_eq!(js::pad_start("abc", 2, Some("0")), "abc");
+    assert_eq!(js::pad_start("abc", 6, Some("")), "abc");
+    assert_eq!(js::pad_start("\u{1F600}", 3, Some("é")), "é\u{1F600}"); // Length 2 in UTF-16
+    assert_eq!(js::pad_start("a", 2, Some("\u{1F600}")), "\u{FFFD}a"); // Truncated surrogate pair
+    assert_eq!(js::pad_end("abc", 6, Some("12")), "abc121");
+    assert_eq!(js::pad_end("abc", 5, None), "abc  ");
+    assert_eq!(js::pad_end("", 3, Some("\u{1F600}")), "\u{1F600}\u{FFFD}");
+
+    assert_eq!(js::repeat("ab", 3), "ababab");
+    assert_eq!(js::repeat("ab", 0), "");
+
+    assert_eq!(js::split("a,b,c", Some(","), None), ["a", "b", "c"]);
+    assert_eq!(js::split("a,b,c", Some(","), Some(2)), ["a", "b"]);
+    assert_eq!(js::split("a,b,c", Some(","), Some(0)), Vec::<String>::new());
+    assert_eq!(js::split("a,b,", Some(","), None), ["a", "b", ""]);
+    assert_eq!(js::split("a,b,c", None, None), ["a,b,c"]);
+    assert_eq!(js::split("a,b,c", None, Some(0)), Vec::<String>::new());
+    assert_eq!(js::split("é\u{1F600}", Some(""), None), ["é", "\u{FFFD}", "\u{FFFD}"]);
+    assert_eq!(js::split("abc", Some(""), Some(2)), ["a", "b"]);
+    assert_eq!(js::split("", Some(","), None), [""]);
+    assert_eq!(js::split("", Some(""), None), Vec::<String>::new());
+    assert_eq!(js::split("a\u{1F600}b", Some("\u{1F600}"), None), ["a", "b"]);
+}
+
+// -----------------------------------------------------------------------------
+
+#[test]
+fn test_utf16() {
+    let s1: &str = "é\u{1F600}b\u{1F44D}"; // UTF-16: é 0, 😀 1-2, b 3, 👍 4-5
+    let s2: String = s1.to_owned();
+
+    assert_eq!(utf16::len(s1), 6);
+    assert_eq!(utf16::len(""), 0);
+
+    // Conversions
+    assert_eq!(utf16::char_index_to_utf16(s1, 0), Ok(0));
+    assert_eq!(utf16::char_index_to_utf16(s1, 2), Ok(3));
+    assert_eq!(utf16::char_index_to_utf16(s1, 4), Ok(6));
+    assert_eq!(utf16::char_index_to_utf16(s1, 5), Err(Utf16IndexError::OutOfRange { index: 5, len: 4 }));
+    assert_eq!(utf16::utf16_to_char_index(s1, 1), Ok(1));
+    assert_eq!(utf16::utf16_to_char_index(s1, 3), Ok(2));
+    assert_eq!(utf16::utf16_to_char_index(s1, 6), Ok(4));
+    assert_eq!(utf16::utf16_to_char_index(s1, 2), Err(Utf16IndexError::SplitSurrogatePair { index: 2 }));
+    assert_eq!(utf16::utf16_to_char_index(s1, 5), Err(Utf16IndexError::SplitSurrogatePair { index: 5 }));
+    assert_eq!(utf16::utf16_to_char_index(s1, 7), Err(Utf16IndexError::OutOfRange { index: 7, len: 6 }));
+    assert_eq!(utf16::utf16_to_byte(s1, 1), Ok(2));
+    assert_eq!(utf16::utf16_to_byte(s1, 3), Ok(6));
+    assert_eq!(utf16::utf16_to_byte(s1, 6), Ok(s1.len()));
+    assert_eq!(utf16::utf16_to_byte(s1, 2), Err(Utf16IndexError::SplitSurrogatePair { index: 2 }));
+    assert_eq!(utf16::utf16_to_byte("", 0), Ok(0));
+    for (char_index, (byte_index, _)) in s1.char_indices().enumerate() {
+        let utf16_index: usize = utf16::char_index_to_utf16(s1, char_index).unwrap();
+        assert_eq!(utf16::utf16_to_char_index(s1, utf16_index), Ok(char_index));
+        assert_eq!(utf16::utf16_to_byte(s1, utf16_index), Ok(byte_index));
+    }
+    assert_eq!(
+        Utf16IndexError::SplitSurrogatePair { index: 2 }.to_string(),
+        "UTF-16 index 2 splits a surrogate pair"
+    );
+
+    // indexof
+    assert_eq!(utf16::indexof(s1, "b", 0), Some(3));
+    assert_eq!(utf16::indexof(s1, "\u{1F44D}", 0), Some(4));
+    assert_eq!(utf16::indexof(s1, "\u{1F600}", 1), Some(1));
+    assert_eq!(utf16::indexof(s1, "\u{1F600}", 2), None); // Starts at the next character
+    assert_eq!(utf16::indexof(s1, "b", 2), Some(3));
+    assert_eq!(utf16::indexof(s1, "b", 4), None);
+    assert_eq!(utf16::indexof(s1, "b", 100), None);
+    assert_eq!(utf16::indexof(s1, "", 0), None);
+
+    // substr, substring, str_remove
+    assert_eq!(utf16::substr(s1, 1, 2), "\u{1F600}");
+    assert_eq!(utf16::substr(s1, -3, 3), "b\u{1F44D}");
+    assert_eq!(utf16::substr(s1, 3, -3), "\u{1F600}b");
+    assert_eq!(utf16::substr(s1, 1, 1), "\u{FFFD}");
+    assert_eq!(utf16::substr(s1, 6, 1), "");
+    assert_eq!(utf16::substr(s1, 0, isize::MAX), s1);
+    assert_eq!(utf16::substring(s1, 3, 1), "\u{1F600}");
+    assert_eq!(utf16::substring(s1, -2, 100), s1);
+    assert_eq!(utf16::str_remove(s1, 1, 2), "éb\u{1F44D}");
+    assert_eq!(utf16::str_remove(s1, -2, 2), "é\u{1F600}b");
+    assert_eq!(utf16::str_remove(s1, 2, 1), "é\u{FFFD}b\u{1F44D}");
+    assert_eq!(utf16::str_remove(s1, 10, 1), s1);
+    assert_eq!(utf16::str_remove("", 0, 1), "");
+
+    // Methods
+    assert_eq!(s1.utf16_len(), 6);
+    assert_eq!(s2.utf16_len(), 6);
+    assert_eq!(s1.utf16_indexof("b", 0), Some(3));
+    assert_eq!(s2.utf16_indexof("b", 0), Some(3));
+    assert_eq!(s1.utf16_substr(4, 2), "\u{1F44D}");
+    assert_eq!(s2.utf16_substr(4, 2), "\u{1F44D}");
+    assert_eq!(s1.utf16_substring(0, 3), "é\u{1F600}");
+    assert_eq!(s2.utf16_substring(0, 3), "é\u{1F600}");
+    assert_eq!(s1.utf16_str_remove(0, 3), "b\u{1F44D}");
+    assert_eq!(s2.utf16_str_remove(0, 3), "b\u{1F44D}");
+}
+
+// -----------------------------------------------------------------------------
+
+#[test]
+fn test_positions() {
+    let s1: &str = "aé\u{1F600}b"; // Bytes: a 0, é 1-2, 😀 3-6, b 7. UTF-16: a 0, é 1, 😀 2-3, b 4
+
+    // Bytes and characters
+    assert_eq!(positions::byte_to_char(s1, 0, Snap::Exact), Some(0));
+    assert_eq!(positions::byte_to_char(s1, 3, Snap::Exact), Some(2));
+    assert_eq!(positions::byte_to_char(s1, 8, Snap::Exact), Some(4));
+    assert_eq!(positions::byte_to_char(s1, 9, Snap::Exact), None);
+    assert_eq!(positions::byte_to_char(s1, 4, Snap::Exact), None);
+    assert_eq!(positions::byte_to_char(s1, 4, Snap::Backward), Some(2));
+    assert_eq!(positions::byte_to_char(s1, 6, Snap::Forward), Some(3));
+    assert_eq!(positions::byte_to_char(s1, usize::MAX, Snap::Backward), None);
+    assert_eq!(positions::char_to_byte(s1, 2), Some(3));
+    assert_eq!(positions::char_to_byte(s1, 4), Some(8));
+    assert_eq!(positions::char_to_byte(s1, 5), None);
+    assert_eq!(positions::char_to_byte("", 0), Some(0));
+    for (char_index, (byte_index, _)) in s1.char_indices().enumerate() {
+        assert_eq!(positions::char_to_byte(s1, char_index), Some(byte_index));
+        assert_eq!(positions::byte_to_char(s1, byte_index, Snap::Exact), Some(char_index));
+    }
+
+    assert_eq!(positions::byte_range_to_char_range(s1, 1..7, Snap::Exact), Some(1..3));
+    assert_eq!(positions::byte_range_to_char_range(s1, 2..5, Snap::Exact), None);
+    assert_eq!(positions::byte_range_to_char_range(s1, 2..5, Snap::Backward), Some(1..2));
+    assert_eq!(positions::byte_range_to_char_range(s1, 2..5, Snap::Forward), Some(2..3));
+    assert_eq!(positions::byte_range_to_char_range(s1, 0..100, Snap::Forward), None);
+    assert_eq!(positions::char_range_to_byte_range(s1, 1..3), Some(1..7));
+    assert_eq!(positions::char_range_to_byte_range(s1, 0..4), Some(0..8));
+    assert_eq!(positions::char_range_to_byte_range(s1, 3..5), None);
+    let (start, end): (usize, usize) = (3, 1);
+    assert_eq!(positions::char_range_to_byte_range(s1, start..end), None);
+    assert_eq!(&s1[positions::char_range_to_byte_range(s1, 2..3).unwrap()], "\u{1F600}");
+
+    // Bytes and UTF-16
+    assert_eq!(positions::byte_to_utf16(s1, 3, Snap::Exact), Some(2));
+    assert_eq!(positions::byte_to_utf16(s1, 7, Snap::Exact), Some(4));
+    assert_eq!(positions::byte_to_utf16(s1, 8, Snap::Exact), Some(5));
+    assert_eq!(positions::byte_to_utf16(s1, 5, Snap::Backward), Some(2));
+    assert_eq!(positions::utf16_to_byte_snapped(s1, 2, Snap::Exact), Some(3));
+    assert_eq!(positions::utf16_to_byte_snapped(s1, 3, Snap::Exact), None); // Inside the surrogate pair
+    assert_eq!(positions::utf16_to_byte_snapped(s1, 3, Snap::Backward), Some(3));
+    assert_eq!(positions::utf16_to_byte_snapped(s1, 3, Snap::Forward), Some(7));
+    assert_eq!(positions::utf16_to_byte_snapped(s1, 6, Snap::Forward), None);
+    assert_eq!(positions::byte_range_to_utf16_range(s1, 3..8, Snap::Exact), Some(2..5));
+    assert_eq!(positions::utf16_range_to_byte_range(s1, 2..4, Snap::Exact), Some(3..7));
+    assert_eq!(positions::utf16_range_to_byte_range(s1, 3..4, Snap::Exact), None);
+    assert_eq!(positions::utf16_range_to_byte_range(s1, 3..4, Snap::Forward), Some(7..7));
+
+    // Bytes and graphemes
+    let s2: &str = "ae\u{301}\u{1F44D}\u{1F3FD}c"; // Graphemes: a 0, é 1-3, 👍🏽 4-11, c 12
+    assert_eq!(positions::byte_to_grapheme(s2, 1, Snap::Exact), Some(1));
+    assert_eq!(positions::byte_to_grapheme(s2, 2, Snap::Exact), None); // Before the combining acute
+    assert_eq!(positions::byte_to_grapheme(s2, 2, Snap::Backward), Some(1));
+    assert_eq!(positions::byte_to_grapheme(s2, 8, Snap::Forward), Some(3));
+    assert_eq!(positions::byte_to_grapheme(s2, 13, Snap::Exact), Some(4));
+    assert_eq!(positions::grapheme_to_byte(s2, 2), Some(4));
+    assert_eq!(positions::grapheme_to_byte(s2, 4), Some(13));
+    assert_eq!(positions::grapheme_to_byte(s2, 5), None);
+    assert_eq!(positions::byte_range_to_grapheme_range(s2, 2..6, Snap::Backward), Some(1..2));
+    assert_eq!(positions::byte_range_to_grapheme_range(s2, 2..6, Snap::Forward), Some(2..3));
+    assert_eq!(positions::grapheme_range_to_byte_range(s2, 1..3), Some(1..12));
+    assert_eq!(&s2[positions::grapheme_range_to_byte_range(s2, 2..3).unwrap()], "\u{1F44D}\u{1F3FD}");
+}
+
+// -----------------------------------------------------------------------------
+
+#[test]
+fn test_line_index() {
+    let s1: &str = "ab\r\né\u{1F600}x\n\rlast \u{1F44D}";
+    // Lines: "ab" 0-2, "é😀x" 4-7, "" 8-8, "last 👍" 9-15
+    let index: LineIndex = LineIndex::new(s1);
+    let pos = |line: usize, column: usize| Position { line, column };
+    use PositionEncoding::{Utf16, Utf32, Utf8};
+
+    assert_eq!(index.line_count(), 4);
+    assert_eq!(index.len(), 15);
+    assert!(!index.is_empty());
+    assert_eq!(index.line_range(0), Some(0..2));
+    assert_eq!(index.line_range(1), Some(4..7));
+    assert_eq!(index.line_range(2), Some(8..8));
+    assert_eq!(index.line_range(3), Some(9..15));
+    assert_eq!(index.line_range(4), None);
+
+    assert_eq!(index.position(0, Utf16), Some(pos(0, 0)));
+    assert_eq!(index.position(3, Utf16), Some(pos(0, 3))); // Inside "\r\n"
+    assert_eq!(index.position(6, Utf32), Some(pos(1, 2)));
+    assert_eq!(index.position(6, Utf16), Some(pos(1, 3)));
+    assert_eq!(index.position(6, Utf8), Some(pos(1, 6)));
+    assert_eq!(index.position(8, Utf16), Some(pos(2, 0)));
+    assert_eq!(index.position(15, Utf16), Some(pos(3, 7)));
+    assert_eq!(index.position(16, Utf16), None);
+
+    assert_eq!(index.offset(pos(1, 3), Utf16), Some(6));
+    assert_eq!(index.offset(pos(1, 6), Utf8), Some(6));
+    assert_eq!(index.offset(pos(1, 2), Utf16), Some(5)); // Inside the surrogate pair
+    assert_eq!(index.offset(pos(1, 1), Utf8), Some(4)); // Inside "é"
+    assert_eq!(index.offset(pos(1, 100), Utf16), Some(7)); // End of the line
+    assert_eq!(index.offset(pos(2, 1), Utf16), Some(8));
+    assert_eq!(index.offset(pos(3, usize::MAX), Utf8), Some(15));
+    assert_eq!(index.offset(pos(4, 0), Utf16), None);
+    assert_eq!(index.convert(pos(3, 7), Utf16, Utf8), Some(pos(3, 9)));
+
+    // Reference: count the units of every character
+    for s in ["", "\n", "\r\r\n\n", "aé\u{1F600}\u{10348}z\r\n\u{1F600}\u{1F600}\nx", s1] {
+        let index: LineIndex = LineIndex::from(s);
+        let chars: Vec<char> = s.chars().collect();
+        let mut line_starts: Vec<usize> = vec![0];
+        for (i, &c) in chars.iter().enumerate() {
+            if c == '\n' || (c == '\r' && chars.get(i + 1) != Some(&'\n')) {
+                line_starts.push(i + 1);
+            }
+        }
+        assert_eq!(index.line_count(), line_starts.len());
+
+        for offset in 0..=chars.len() {
+            let line: usize = line_starts.iter().rposition(|&start| start <= offset).unwrap();
+            let before = &chars[line_starts[line]..offset];
+            for (encoding, column) in [
+                (Utf8, before.iter().map(|c| c.len_utf8()).sum::<usize>()),
+                (Utf16, before.iter().map(|c| c.len_utf16()).sum::<usize>()),
+                (Utf32, before.len()),
+            ] {
+                assert_eq!(index.position(offset, encoding), Some(pos(line, column)));
+                if offset <= index.line_range(line).unwrap().end {
+                    assert_eq!(index.offset(pos(line, column), encoding), Some(offset));
+                }
+            }
+        }
+    }
+
+    let empty: LineIndex = LineIndex::new("");
+    assert_eq!(empty.line_count(), 1);
+    assert!(empty.is_empty());
+    assert_eq!(empty.position(0, Utf16), Some(pos(0, 0)));
+    assert_eq!(empty.offset(pos(0, 5), Utf16), Some(0));
+}
+
+// -----------------------------------------------------------------------------
+
+#[test]
+fn test_display_width() {
+    assert_eq!(char_width('a'), 1);
+    assert_eq!(char_width('\t'), 0);
+    assert_eq!(char_width('é'), 1);
+    assert_eq!(char_width('日'), 2);
+    assert_eq!(char_width('\u{FF21}'), 2); // Fullwidth A
+    assert_eq!(char_width('\u{1F600}'), 2);
+    assert_eq!(char_width('\u{301}'), 0);
+    assert_eq!(char_width('\u{200D}'), 0); // Zero width joiner
+    assert_eq!(char_width('\u{FE0F}'), 0);
+    assert_eq!(char_width('\u{1161}'), 0); // Hangul Jungseong
+
+    assert_eq!(display_width(""), 0);
+    assert_eq!(display_width("abc"), 3);
+    assert_eq!(display_width("日本語"), 6);
+    assert_eq!(display_width("Cafe\u{301}"), 4);
+    assert_eq!(display_width("a\u{1F600}b"), 4);
+    assert_eq!(display_width("\u{1100}\u{1161}"), 2); // Hangul syllable from jamo
+
+    let s1: &str = "日本語 text";
+    assert_eq!(substr_width(s1, 0, 4), "日本");
+    assert_eq!(substr_width(s1, 0, 5), "日本");
+    assert_eq!(substr_width(s1, 1, 4), "本");
+    assert_eq!(substr_width(s1, 2, 4), "本語");
+    assert_eq!(substr_width(s1, 6, 3), " te");
+    assert_eq!(substr_width(s1, 9, 100), "xt");
+    assert_eq!(substr_width(s1, 11, 1), "");
+    assert_eq!(substr_width(s1, 0, 0), "");
+    assert_eq!(substr_width(s1, 0, usize::MAX), s1);
+    assert_eq!(substr_width(s1, usize::MAX, usize::MAX), "");
+
+    // Zero-width characters stay with the character before them
+    let s2: &str = "ae\u{301}b\u{301}\u{302}";
+    assert_eq!(substr_width(s2, 1, 1), "e\u{301}");
+    assert_eq!(substr_width(s2, 0, 1), "a");
+    assert_eq!(substr_width(s2, 2, 1), "b\u{301}\u{302}");
+    assert_eq!(substr_width("\u{301}ab", 0, 1), "\u{301}a");
+    assert_eq!(substr_width("\u{301}ab", 1, 1), "b");
+
+    assert_eq!(truncate_width(s1, 11, "…"), s1);
+    assert_eq!(truncate_width(s1, 8, "…"), "日本語 …");
+    assert_eq!(truncate_width(s1, 6, "…"), "日本…");
+    assert_eq!(truncate_width(s1, 4, "..."), "...");
+    assert_eq!(truncate_width(s1, 2, "..."), "..");
+    assert_eq!(truncate_width(s1, 0, "…"), "");
+    assert_eq!(truncate_width("Cafe\u{301} au lait", 6, "…"), "Cafe\u{301} …");
+    assert_eq!(truncate_width("\u{1F600}\u{1F600}\u{1F600}", 5, "…"), "\u{1F600}\u{1F600}…");
+}
+
+// -----------------------------------------------------------------------------
+
+#[test]
+fn test_pad() {
+    assert_eq!(pad_left("5", 3, "0"), "005");
+    assert_eq!(pad_left("abc", 10, "123"), "1231231abc");
+    assert_eq!(pad_left("abc", 3, "0"), "abc");
+    assert_eq!(pad_left("abcdef", 3, "0"), "abcdef");
+    assert_eq!(pad_left("abc", 10, ""), "abc");
+    assert_eq!(pad_left("", 3, "é"), "ééé");
+    assert_eq!(pad_right("abc", 6, "é."), "abcé.é");
+    assert_eq!(pad_right("Total", 10, ". "), "Total. . .");
+    assert_eq!(pad_center("ab", 6, "*", CenterBias::Left), "**ab**");
+    assert_eq!(pad_center("abc", 8, "-", CenterBias::Left), "--abc---");
+    assert_eq!(pad_center("abc", 8, "-", CenterBias::Right), "---abc--");
+    assert_eq!(pad_center("abc", 9, "12", CenterBias::Left), "121abc121");
+    assert_eq!(pad_center("abc", 4, "12", CenterBias::Right), "1abc");
+    assert_eq!(format!("{:^8}", "abc"), pad_center("abc", 8, " ", CenterBias::default()));
+
+    // Characters, not bytes
+    assert_eq!(pad_left("éèçà", 6, "😀"), "😀😀éèçà");
+    assert_eq!("éèçà".pad_right(5, "-"), "éèçà-");
+    assert_eq!("éèçà".to_owned().pad_left(5, "-"), "-éèçà");
+    assert_eq!("éèçà".pad_center(7, "ü", CenterBias::Right), "üüéèçàü");
+    assert_eq!(CharIndex::new("éèçà").pad_left(6, "0"), "00éèçà");
+
+    // Terminal columns
+    assert_eq!(pad_with("日本", 8, "-", PadSide::Left, WidthMode::Columns), "----日本");
+    assert_eq!(pad_with("日本", 8, "-", PadSide::Left, WidthMode::Chars), "------日本");
+    assert_eq!(pad_with("ab", 5, "日", PadSide::Right, WidthMode::Columns), "ab日 ");
+    assert_eq!(pad_with("ab", 6, "日", PadSide::Right, WidthMode::Columns), "ab日日");
+    assert_eq!(pad_with("e\u{301}", 3, ".", PadSide::Center(CenterBias::Left), WidthMode::Columns), ".e\u{301}.");
+    assert_eq!(pad_with("ab", 6, "\u{301}", PadSide::Right, WidthMode::Columns), "ab");
+    assert_eq!(pad_with("日本語", 4, "-", PadSide::Right, WidthMode::Columns), "日本語");
+}
+
+// -----------------------------------------------------------------------------
+
+#[test]
+fn test_truncate_chars() {
+    let s1: &str = "Café au lait with milk";
+    let options = |boundary: TruncateBoundary, position: TruncatePosition| TruncateOptions {
+        boundary,
+        position,
+        ..Default::default()
+    };
+    use TruncateBoundary::{Char, Grapheme, Word};
+    use TruncatePosition::{End, Middle, Start};
+
+    assert_eq!(truncate_chars(s1, 22, TruncateOptions::default()), s1);
+    assert_eq!(truncate_chars(s1, 16, TruncateOptions::default()), "Café au lait wi…");
+    assert_eq!(truncate_chars(s1, 16, options(Word, End)), "Café au lait…");
+    assert_eq!(truncate_chars(s1, 13, options(Word, End)), "Café au lait…");
+    assert_eq!(truncate_chars(s1, 12, options(Word, End)), "Café au…");
+    assert_eq!(truncate_chars(s1, 16, options(Char, Start)), "… lait with milk");
+    assert_eq!(truncate_chars(s1, 16, options(Word, Start)), "…lait with milk");
+    assert_eq!(truncate_chars(s1, 14, options(Word, Start)), "…with milk");
+    assert_eq!(truncate_chars(s1, 16, options(Char, Middle)), "Café au …th milk");
+    assert_eq!(truncate_chars(s1, 16, options(Word, Middle)), "Café au…milk");
+    assert_eq!(truncate_chars(s1, 1, TruncateOptions::default()), "…");
+    assert_eq!(truncate_chars(s1, 0, TruncateOptions::default()), "");
+
+    // The ellipsis counts toward max
+    let dots = TruncateOptions { ellipsis: "...", ..Default::default() };
+    assert_eq!(truncate_chars("abcdefgh", 6, dots), "abc...");
+    assert_eq!(truncate_chars("abcdefgh", 3, dots), "...");
+    assert_eq!(truncate_chars("abcdefgh", 2, dots), "..");
+    assert_eq!(truncate_chars("abcdefgh", 6, TruncateOptions { ellipsis: "", ..dots }), "abcdef");
+
+    // A word that is too long is cut at a character boundary
+    assert_eq!(truncate_chars("Internationalization", 10, options(Word, End)), "Internati…");
+    assert_eq!(truncate_chars("Internationalization", 10, options(Word, Start)), "…alization");
+
+    // No whitespace next to the ellipsis
+    assert_eq!(truncate_chars("   abcdef", 4, options(Word, End)), "…");
+    assert_eq!(truncate_chars("abcdef   ", 4, options(Word, Start)), "…");
+    assert_eq!(truncate_chars("ab   cdef", 4, options(Word, End)), "ab…");
+    assert_eq!(truncate_chars("abcd   ef", 4, options(Word, Start)), "…ef");
+    assert_eq!(truncate_chars("a  bcdefgh  i", 7, options(Word, Middle)), "a…i");
+
+    // Graphemes: "e" + combining acute stays together
+    let s2: &str = "Cafe\u{301} au lait";
+    assert_eq!(truncate_chars(s2, 5, options(Char, End)), "Cafe…");
+    assert_eq!(truncate_chars(s2, 6, options(Char, End)), "Cafe\u{301}…");
+    assert_eq!(truncate_chars(s2, 5, options(Grapheme, End)), "Caf…");
+    assert_eq!(truncate_chars("a\u{301}bcd", 3, options(Char, Start)), "…cd");
+    assert_eq!(truncate_chars("ab\u{301}cd", 4, options(Grapheme, Start)), "…cd");
+    assert_eq!(truncate_chars("ab\u{301}cd", 4, options(Char, Start)), "…\u{301}cd");
+    assert_eq!(truncate_chars("\u{1F44D}\u{1F3FD}\u{1F44D}\u{1F3FD}", 3, options(Grapheme, End)), "\u{1F44D}\u{1F3FD}…");
+}
+
+// -----------------------------------------------------------------------------
+
+#[test]
+fn test_wrap() {
+    assert_eq!(wrap("The quick brown fox", 10), ["The quick", "brown fox"]);
+    assert_eq!(wrap("The quick brown fox", 9), ["The quick", "brown fox"]);
+    assert_eq!(wrap("The quick brown fox", 8), ["The", "quick", "brown", "fox"]);
+    assert_eq!(wrap("The quick brown fox", 100), ["The quick brown fox"]);
+    assert_eq!(wrap("  The   quick\tbrown  ", 100), ["The quick brown"]);
+    assert_eq!(wrap("", 10), Vec::<String>::new());
+    assert_eq!(wrap("Café au lait\nwith milk", 8), ["Café au", "lait", "with", "milk"]);
+    assert_eq!(wrap("éèçà éèçà éèçà", 9), ["éèçà éèçà", "éèçà"]);
+
+    // Line endings
+    assert_eq!(wrap("one\n\ntwo three\r\nfour", 20), ["one", "", "two three", "four"]);
+    let joined = WrapOptions { preserve_newlines: false, ..Default::default() };
+    assert_eq!(wrap_with("one\n\ntwo three\r\nfour", 20, joined), ["one two three four"]);
+    assert_eq!(wrap_with("", 20, joined), Vec::<String>::new());
+
+    // Long words
+    assert_eq!(wrap("a verylongword b", 5), ["a", "veryl", "ongwo", "rd b"]);
+    let no_break = WrapOptions { break_words: false, ..Default::default() };
+    assert_eq!(wrap_with("a verylongword b", 5, no_break), ["a", "verylongword", "b"]);
+    assert_eq!(wrap("abc", 0), ["a", "b", "c"]);
+    assert_eq!(wrap("e\u{301}e\u{301}e\u{301}", 3), ["e\u{301}", "e\u{301}", "e\u{301}"]);
+    assert_eq!(wrap("e\u{301}e\u{301}e\u{301}", 4), ["e\u{301}e\u{301}", "e\u{301}"]);
+
+    // Indents
+    let list = WrapOptions { initial_indent: "- ", subsequent_indent: "  ", ..Default::default() };
+    assert_eq!(wrap_with("The quick brown fox", 10, list), ["- The", "  quick", "  brown", "  fox"]);
+    assert_eq!(wrap_with("The quick brown fox\njumps", 12, list), ["- The quick", "  brown fox", "- jumps"]);
+    let quote = WrapOptions { initial_indent: "> ", subsequent_indent: "> ", ..Default::default() };
+    assert_eq!(wrap_with("aaa bbb ccc", 9, quote), ["> aaa bbb", "> ccc"]);
+
+    // An indent that is not narrower than the width is cut to width - 1
+    let wide = WrapOptions { initial_indent: ">>>>>>", ..Default::default() };
+    assert_eq!(wrap_with("ab cd", 4, wide), [">>>a", "b cd"]);
+    let wide = WrapOptions { initial_indent: "- ", subsequent_indent: "      ", ..Default::default() };
+    assert_eq!(wrap_with("ab cd", 3, wide), ["- a", "  b", "  c", "  d"]);
+    let wide = WrapOptions { initial_indent: "日本語", mode: WidthMode::Columns, ..Default::default() };
+    assert_eq!(wrap_with("ab", 4, wide), ["日ab"]);
+    assert_eq!(wrap_with("abc", 4, wide), ["日ab", "c"]);
+    assert!(wrap_with("a bc def", 5, wide).iter().all(|line| display_width(line) <= 5));
+
+    // Terminal columns
+    let columns = WrapOptions { mode: WidthMode::Columns, ..Default::default() };
+    assert_eq!(wrap_with("日本語 テキスト", 8, columns), ["日本語", "テキスト"]);
+    assert_eq!(wrap_with("日本語テキスト", 5, columns), ["日本", "語テ", "キス", "ト"]);
+    assert_eq!(wrap_with("日本 ab", 7, columns), ["日本 ab"]);
+    assert_eq!(wrap("日本語テキスト", 5), ["日本語テキ", "スト"]);
+}
+
+// -----------------------------------------------------------------------------
+
+#[test]
+fn test_justify() {
+    let s1: &str = "The quick brown fox jumps";
+    assert_eq!(justify(s1, 11, Alignment::Left), ["The quick", "brown fox", "jumps"]);
+    assert_eq!(justify(s1, 11, Alignment::Right), ["  The quick", "  brown fox", "      jumps"]);
+    assert_eq!(justify(s1, 11, Alignment::Center), [" The quick", " brown fox", "   jumps"]);
+    assert_eq!(justify(s1, 11, Alignment::Full), ["The   quick", "brown   fox", "jumps"]);
+    assert_eq!(justify(s1, 16, Alignment::Full), ["The  quick brown", "fox jumps"]);
+    assert_eq!(justify("", 10, Alignment::Full), Vec::<String>::new());
+
+    // Every line of the text is a paragraph, its last line is not justified
+    let s2: &str = "Café au lait with milk\n\nà la carte";
+    assert_eq!(
+        justify(s2, 10, Alignment::Full),
+        ["Café    au", "lait  with", "milk", "", "à la carte"]
+    );
+
+    // Empty and whitespace-only lines stay empty, without padding
+    for alignment in [Alignment::Right, Alignment::Center, Alignment::Full] {
+        assert_eq!(justify("abc\n   \n\ndef", 6, alignment)[1..3], ["", ""]);
+        assert_eq!(justify_line("", 6, alignment, WidthMode::Chars), "");
+        assert_eq!(justify_line("   ", 6, alignment, WidthMode::Columns), "");
+    }
+    assert_eq!(justify("abc\n   \ndef", 6, Alignment::Right), ["   abc", "", "   def"]);
+
+    assert_eq!(justify_line("a b c", 9, Alignment::Full, WidthMode::Chars), "a   b   c");
+    assert_eq!(justify_line("a b c", 8, Alignment::Full, WidthMode::Chars), "a   b  c");
+    assert_eq!(justify_line("a  b c", 3, Alignment::Full, WidthMode::Chars), "a b c");
+    assert_eq!(justify_line(" word ", 8, Alignment::Full, WidthMode::Chars), "word");
+    assert_eq!(justify_line("abc", 8, Alignment::Center, WidthMode::Chars), "  abc");
+    assert_eq!(justify_line("éèç", 5, Alignment::Right, WidthMode::Chars), "  éèç");
+    assert_eq!(justify_line("abcdef", 3, Alignment::Right, WidthMode::Chars), "abcdef");
+
+    // Terminal columns
+    assert_eq!(
+        justify_with("日本語 テキスト", 10, Alignment::Center, WidthMode::Columns),
+        ["  日本語", " テキスト"]
+    );
+    assert_eq!(justify_line("日本 語", 9, Alignment::Full, WidthMode::Columns), "日本   語");
+    assert_eq!(justify_line("日本", 6, Alignment::Right, WidthMode::Columns), "  日本");
+
+    // Columns
+    let rows = [["Item", "Qty", "Note"], ["Café", "2", ""], ["Thé", "12", "hot"]];
+    let alignments = [Alignment::Left, Alignment::Right, Alignment::Center];
+    assert_eq!(
+        align_columns(&rows, &alignments, " | ", WidthMode::Chars),
+        ["Item | Qty | Note", "Café |   2 |", "Thé  |  12 | hot"]
+    );
+    let rows: Vec<Vec<String>> = vec![vec!["日本".to_owned(), "x".to_owned()], vec!["abc".to_owned()]];
+    assert_eq!(align_columns(&rows, &[Alignment::Right], " ", WidthMode::Columns), ["日本 x", " abc"]);
+    assert_eq!(align_columns(&rows, &[], " ", WidthMode::Chars), ["日本  x", "abc"]);
+}